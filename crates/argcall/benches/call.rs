@@ -0,0 +1,122 @@
+//! Compares direct function calls with calls through `call_fn`, which should compile to the same
+//! code once the argument tuple is optimized away, and measures the allocation `call_all` makes
+//! for small and large enums against `call_batch`, which keeps up to 8 outputs inline.
+//!
+//! Run with `cargo bench -p argcall --features smallvec`.
+
+use argcall::Callable;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u64 = 100_000_000;
+const CALL_ALL_ITERATIONS: u64 = 1_000_000;
+
+fn add_one(x: u64) -> u64 {
+    x.wrapping_add(1)
+}
+
+#[derive(Callable)]
+#[argcall(output = u64, args = (x: u64))]
+enum Op {
+    #[argcall(fn = add_one(x))]
+    AddOne,
+}
+
+#[derive(Callable)]
+#[argcall(output = u64, args = (x: u64), fn = add_one(x))]
+struct AddOne;
+
+#[derive(Callable)]
+#[argcall(output = u64, args = (x: u64), dispatch, call_all)]
+#[cfg_attr(feature = "smallvec", argcall(call_batch))]
+enum Pair {
+    #[argcall(fn = x)]
+    First,
+    #[argcall(fn = x + 1)]
+    Second,
+}
+
+#[derive(Callable)]
+#[argcall(output = u64, args = (x: u64), dispatch, call_all)]
+#[cfg_attr(feature = "smallvec", argcall(call_batch))]
+enum Sixteen {
+    #[argcall(fn = x)]
+    V0,
+    #[argcall(fn = x + 1)]
+    V1,
+    #[argcall(fn = x + 2)]
+    V2,
+    #[argcall(fn = x + 3)]
+    V3,
+    #[argcall(fn = x + 4)]
+    V4,
+    #[argcall(fn = x + 5)]
+    V5,
+    #[argcall(fn = x + 6)]
+    V6,
+    #[argcall(fn = x + 7)]
+    V7,
+    #[argcall(fn = x + 8)]
+    V8,
+    #[argcall(fn = x + 9)]
+    V9,
+    #[argcall(fn = x + 10)]
+    V10,
+    #[argcall(fn = x + 11)]
+    V11,
+    #[argcall(fn = x + 12)]
+    V12,
+    #[argcall(fn = x + 13)]
+    V13,
+    #[argcall(fn = x + 14)]
+    V14,
+    #[argcall(fn = x + 15)]
+    V15,
+}
+
+/// Runs `f` for every iteration and prints the time per call, keeping the results observable
+fn bench(name: &str, f: impl FnMut(u64) -> u64) {
+    bench_n(name, ITERATIONS, f);
+}
+
+/// Runs `f` for `iterations` iterations and prints the time per call
+fn bench_n(name: &str, iterations: u64, mut f: impl FnMut(u64) -> u64) {
+    let start = Instant::now();
+    let mut acc = 0u64;
+    for i in 0..iterations {
+        acc = acc.wrapping_add(f(black_box(i)));
+    }
+    let elapsed = start.elapsed();
+    black_box(acc);
+    println!(
+        "{:<24} {:>8.3} ns/iter",
+        name,
+        elapsed.as_nanos() as f64 / iterations as f64
+    );
+}
+
+fn main() {
+    let op = black_box(Op::AddOne);
+    let add = black_box(AddOne);
+    let closure = black_box(|x: u64| add_one(x));
+
+    bench("direct", add_one);
+    bench("closure call_fn", |x| closure.call_fn((x,)));
+    bench("derived enum call_fn", |x| op.call_fn((x,)));
+    bench("derived struct call_fn", |x| add.call_fn((x,)));
+    bench_n("call_all 2 variants", CALL_ALL_ITERATIONS, |x| {
+        Pair::call_all((x,)).iter().sum()
+    });
+    bench_n("call_all 16 variants", CALL_ALL_ITERATIONS, |x| {
+        Sixteen::call_all((x,)).iter().sum()
+    });
+    #[cfg(feature = "smallvec")]
+    {
+        bench_n("call_batch 2 variants", CALL_ALL_ITERATIONS, |x| {
+            Pair::call_batch((x,)).iter().sum()
+        });
+        bench_n("call_batch 16 variants", CALL_ALL_ITERATIONS, |x| {
+            Sixteen::call_batch((x,)).iter().sum()
+        });
+    }
+}
@@ -1,4 +1,8 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 /// The `Callable` macro derives functionality that enables enums and structs to be directly callable,
 /// associating custom functions or methods with specific variants or fields.
@@ -12,23 +16,602 @@
 /// ### Attributes
 ///
 /// - `#[argcall(output = <Type>)]`: Specifies the return type for the `call_fn` method. This should match the
-///   output type of the functions bound to the variants or fields.
+///   output type of the functions bound to the variants or fields. On an enum it may be omitted when a tuple
+///   variant delegates to an inner callable, in which case the output of the first such variant is used.
+///   Otherwise it defaults to `()`, for types that are only called for their side effects. The type may
+///   also be written as a string, such as `output = "Vec<i32>"`, like the path of `fn_path`.
 /// - `#[argcall(fn = <function()>)]`: Binds a specific function to the variant. The function is invoked when
-///   `call_fn` is called on the variant.
+///   `call_fn` is called on the variant. Any expression may be bound, including a block such as
+///   `{ let x = 1; x + 1 }` for logic that does not warrant a helper function.
 /// - `#[argcall(fn_path = "<function_path>")]`: Binds a function by path, allowing the use of functions
-///   located in other modules or namespaces.
+///   located in other modules or namespaces. The fields are passed in declaration order. Generic functions
+///   may be given their type arguments with a turbofish, as in `fn_path = "parse::<u32>"`.
+/// - `#[argcall(fn_path = "<function_path>", arg_order = [<field>, ...])]`: Passes the listed fields to the
+///   function in the given order instead, for functions whose parameters are ordered differently. Listing
+///   `self` passes the receiver, so `fn_path = "Self::step", arg_order = [self]` calls a method such as
+///   `fn step(&mut self)` on the value itself, and its changes to the fields are kept.
 /// - `#[argcall(fn = <function(arg)>) or fn_path = "<function_path(arg)>"]`: Allows binding a function with
 ///   an argument, typically used for named fields that provide a specific value to the function.
+/// - `#[argcall(output = <Type>)]` on a variant: Overrides the output of that variant. The value is converted
+///   into the enum output with `Into`, while the struct generated for a unit variant keeps the variant output.
+/// - `#[argcall(try_output = <Type>, error = <Type>)]`: Shorthand for `output = Result<<try_output>, <error>>`,
+///   making the type a [`TryCallable`].
+/// - `#[argcall(args = (<name>: <Type>, ...))]`: Declares the arguments taken by `call_fn`. The generated
+///   impl uses the argument tuple as `Args`, the names are bound in every `fn` expression, and tuple
+///   variants forward the whole tuple to their inner callable, which must implement the derived trait for
+///   these arguments; otherwise the error is reported at the field. Irrefutable patterns such as
+///   `(x, y): (i32, i32)` may be used in place of names.
+/// - `#[argcall(ctx = <Type>)]`: Passes a context shared by every variant, such as `&Context`, as the first
+///   argument before those declared with `args`, and binds it as `ctx` in every `fn` expression.
+/// - `#[argcall(fn = <function(_0)>)]` on a tuple variant: Binds the fields as `_0`, `_1`, ... and calls
+///   the function instead of forwarding to the inner value, which then does not need to be callable.
+///   A tuple variant without `fn` must have exactly one field.
+/// - `#[argcall(delegate = "ref")]` on a tuple variant forwarding to its inner value: Borrows the inner value
+///   and calls it as a [`Callable`] under `#[derive(CallableMut)]` or `#[derive(CallableOnce)]`, instead of
+///   passing it the same way as `self`, which `delegate = "value"` and the default do.
+/// - `#[argcall(default)]` on a variant: Returns `Default::default()` of the variant output instead of calling
+///   a function, which is handy for placeholder variants.
+/// - `#[argcall(fn = <function()>)]` or `#[argcall(fn_path = "<function_path>")]` on an enum: Binds a fallback
+///   function, called by the unit variants that bind neither a function of their own nor `default`.
+/// - `#[argcall(async_fn = <future>)]` on a variant: Binds an expression evaluating to a future, such as a call
+///   to an `async fn`. An enum with any such variant implements `AsyncCallable` instead
+///   of `Callable`, which requires the `async` feature, and the other variants are evaluated as usual and
+///   returned as ready futures.
+/// - `#[argcall(ref)]` or `#[argcall(ref = '<lifetime>)]`: Implements [`CallableRef`] instead of `Callable`,
+///   so the output may borrow from `self` for the given lifetime, `'a` by default. With an output such as
+///   `Cow<'a, str>`, some variants can borrow their fields while others return owned values.
+/// - `#[argcall(const)]`: Also generates an inherent `const fn eval(&self, args)` with the same body as
+///   `call_fn`, as trait methods cannot be `const`. Every bound `fn` must then be usable in a `const fn`,
+///   so tuple variants delegating to an inner callable and variant-level outputs are not supported.
+/// - `#[argcall(fn_traits)]`: Also implements the nightly `Fn` traits, so the value can be called as
+///   `value()`. See below.
+/// - `#[argcall(crate = <path>)]`: Refers to this crate by the given path in the generated code instead of
+///   `argcall`, such as when the dependency is renamed in `Cargo.toml`.
+/// - `#[argcall(bound = "<predicates>")]`: Adds `where` predicates such as `"T: Display + Clone"` to the
+///   generated impls, on top of the bounds already declared on the type.
+/// - `#[argcall(struct_vis = "<visibility>")]` on an enum: Sets the visibility of the structs generated for
+///   unit variants and of their fields, such as `"pub"` or `"pub(crate)"`. They are private by default.
+/// - `#[argcall(struct_suffix = "<Suffix>")]` on an enum: Names the structs generated for unit variants
+///   `{Enum}{Variant}{Suffix}` instead of `{Enum}{Variant}Callable`.
+/// - `#[argcall(struct_derive(<Trait>, ...))]` on an enum: Derives the given traits, such as `Clone` and `Debug`,
+///   for the structs generated for unit variants. No traits are derived for them by default.
+/// - `#[argcall(struct_name = "<Name>")]` on a unit variant: Names the struct generated for that variant.
+///   Each generated struct also converts back into its variant with `From`.
+/// - `#[argcall(no_structs)]` on an enum: Skips generating the structs for unit variants, so only the enum
+///   itself is callable.
+/// - `#[argcall(allow_empty)]` on an enum: Allows deriving on an enum without variants, which is otherwise
+///   rejected as it can never be called.
+/// - `#[argcall(dispatch)]` on an enum whose variants are all unit variants: Generates
+///   `from_name(&str) -> Option<Self>`, matching the variant names, and `all() -> &'static [Self]`,
+///   listing the variants in declaration order. `TryFrom<&str>` is implemented as well, failing with a
+///   generated `<Enum>NameError` that lists the variant names, along with `name` as for
+///   `#[argcall(name)]`.
+/// - `#[argcall(name)]` on an enum: Generates `const fn name(&self) -> &'static str` returning the name of
+///   the variant, such as for logging, which is its `rename` or else its identifier. Unlike `dispatch`, the
+///   variants may have fields.
+/// - `#[argcall(dispatch, call_all)]` on an enum: Also generates `call_all() -> Vec<Output>`, calling every
+///   variant in declaration order, or `call_all(args)` cloning the arguments when `args` are declared. This
+///   requires the `alloc` feature.
+/// - `#[argcall(dispatch, call_batch)]` on an enum: Generates `call_batch` like `call_all`, but collecting
+///   into a `SmallVec<[Output; 8]>` that does not allocate for up to 8 variants. This requires the
+///   `smallvec` feature.
+/// - `#[argcall(rename = "<name>")]` on a variant of a `dispatch` or `name` enum: Names the variant by the
+///   given name instead of its identifier, such as `"list-all"` for `ListAll`, both in `name` and in the
+///   lookups of `dispatch`.
+/// - `#[argcall(skip)]` on a variant of a `dispatch` enum: Leaves the variant out of `from_name`, `all`,
+///   `TryFrom<&str>`, `call_all` and `call_batch`, so it is only callable when constructed directly.
+///   Skipped variants may have fields.
+/// - `#[argcall(boxed)]` on an enum whose variants are all unit variants: Implements
+///   `From<&Enum>` for `Box<dyn Callable<Args, Output = T>>`, boxing the struct generated for the variant.
+///   This requires the `alloc` feature.
+/// - `#[argcall(catch_unwind)]`: Calls the bound functions inside `std::panic::catch_unwind`, so the output
+///   becomes `Result<T, Box<dyn Any + Send>>` and a panic is returned as the `Err` payload. This requires
+///   the `std` feature and is not supported on async callables.
+/// - `#[argcall(box_output)]`: Passes the values of the bound functions to `Box::new`, so each variant can
+///   return a different type behind a boxed output such as `Box<dyn Iterator<Item = i32>>`. Inner
+///   callables of tuple variants and `default` are not boxed. This requires the `alloc` feature and is
+///   not supported on async callables.
+/// - `#[argcall(enum_output(Iterator, ...))]` on an enum: Generates `<Enum>Output` with a variant of the same
+///   name holding the output of each variant, and makes it the output, so variants can return different
+///   iterators without boxing. Each variant declares its concrete type with `output`, except tuple variants
+///   delegating to an inner callable. The generated enum implements the listed traits out of `Iterator`,
+///   `DoubleEndedIterator` and `ExactSizeIterator` by delegating to the held value. Generic enums and
+///   async callables are not supported.
+///
+/// ```
+/// use argcall::Callable;
+///
+/// #[derive(Callable)]
+/// #[argcall(output = i32, args = (n: i32))]
+/// enum Op {
+///     #[argcall(fn = n * 2)]
+///     Double,
+/// }
+///
+/// assert_eq!(Op::Double.call_fn((21,)), 42);
+/// ```
+///
+/// With `#[argcall(discriminant)]`, the enum also gets a `const fn discriminant(&self)` returning the
+/// discriminant of the variant, such as `1` for `A = 1`, counting up from the previous one for the
+/// variants that do not declare it. It returns the integer type given with `#[repr]`, such as `u8` for
+/// `#[repr(u8)]`, or `isize` without one.
+///
+/// Generic enums and structs are supported, and their generics and `where` clause are carried over
+/// to the generated impls. The structs generated for unit variants of a generic enum hold a
+/// `PhantomData` field for its type and lifetime parameters. Const parameters are carried over as
+/// well, so the output may depend on them, as in `#[argcall(output = [u8; N])]`.
+///
+/// The fields are bound by reference for `Callable`, by mutable reference for `CallableMut`, and by
+/// value for `CallableOnce`, so the functions bound with `#[derive(CallableOnce)]` may consume and
+/// modify them. A field holding a closure, such as `f: Box<dyn Fn() -> i32>`, is called with
+/// `#[argcall(fn = (f)())]`, which takes an `Fn` for `Callable`, an `FnMut` for `CallableMut` and an
+/// `FnOnce` for `CallableOnce`. In every variant, `self` is the value being called, also when it is called through the
+/// struct generated for a unit variant. As the fields are borrowed mutably or moved out of `self` for
+/// `CallableMut` and `CallableOnce`, these can use `self` only where the borrow checker allows it, such
+/// as in unit variants. Likewise, `Self` is the enum type, so associated functions can be bound with
+/// `#[argcall(fn_path = "Self::handle")]` or `#[argcall(fn = Self::handle())]`.
+///
+/// The fields are bound under their own names, which may be anything including `args` or `call_fn`,
+/// as the generated code binds no other names in scope of the bound function. Only the arguments
+/// declared with `args` shadow the fields of the same name.
+///
+/// Variants disabled with `#[cfg(...)]` are removed before the derive sees the enum, so no struct,
+/// match arm or name is generated for them, and `#[cfg_attr(..., argcall(...))]` applies the
+/// attributes only when its condition holds.
+///
+/// `#[non_exhaustive]` enums, variants and structs are supported as they are. The derived impls live
+/// in the defining crate, where matching on them does not need a wildcard arm.
+///
+/// A tuple variant may delegate to another derived enum, which may delegate further, so dispatch can
+/// be nested to any depth as long as every level declares the same `args`. A recursive enum binds
+/// `fn` on its boxed variants instead, such as `#[argcall(fn = -_0.call_fn(()))]` on `Neg(Box<Expr>)`.
+///
+/// On a struct, `fn` or `fn_path` is given next to `output` on the struct itself. Named fields are
+/// bound by name and tuple fields as `_0`, `_1`, ..., so either the bindings or `self` can be used:
+///
+/// ```
+/// use argcall::Callable;
+///
+/// #[derive(Callable)]
+/// #[argcall(output = i32, fn = self.x + self.y)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_eq!(Point { x: 1, y: 2 }.call_fn(()), 3);
+/// ```
+///
+/// With `ref`, the output may borrow from the fields of the value being called:
+///
+/// ```
+/// use argcall::{Callable, CallableRef};
+///
+/// #[derive(Callable)]
+/// #[argcall(ref, output = &'a str)]
+/// enum Greeting {
+///     #[argcall(fn = "hello")]
+///     Hello,
+///     #[argcall(fn = name.as_str())]
+///     Custom { name: String },
+/// }
+///
+/// assert_eq!(Greeting::Hello.call_fn_ref(()), "hello");
+/// assert_eq!(Greeting::Custom { name: "hi".to_string() }.call_fn_ref(()), "hi");
+/// ```
+///
+/// With `#[argcall(fn_traits)]` on a nightly toolchain, the derives also implement `Fn`, `FnMut` and
+/// `FnOnce` for `Callable`, `CallableMut` and `CallableOnce` respectively, so a value can be called
+/// as `value()`. The crate deriving it then needs `#![feature(fn_traits, unboxed_closures)]`, and
+/// `Callable` is provided by its impl for closures. Types without the attribute are unaffected.
+///
+/// Only `argcall` attributes are read, so the derive can be combined with derives owning the other
+/// attributes and fields, such as a `clap` subcommand whose parsed fields are passed to each `fn`:
+///
+/// ```
+/// use argcall::Callable;
+/// use clap::{Parser, Subcommand};
+///
+/// #[derive(Parser)]
+/// struct Cli {
+///     #[command(subcommand)]
+///     command: Command,
+/// }
+///
+/// #[derive(Subcommand, Callable)]
+/// #[argcall(output = Result<(), std::io::Error>)]
+/// enum Command {
+///     /// Prints a greeting
+///     #[argcall(fn = greet(name, *count))]
+///     Greet {
+///         #[arg(long)]
+///         name: String,
+///         #[arg(short, long, default_value_t = 1)]
+///         count: usize,
+///     },
+///     #[argcall(fn = Ok(()))]
+///     Noop,
+/// }
+///
+/// fn greet(name: &str, count: usize) -> Result<(), std::io::Error> {
+///     for _ in 0..count {
+///         println!("hello {}", name);
+///     }
+///     Ok(())
+/// }
+///
+/// let cli = Cli::parse_from(["app", "greet", "--name", "argcall", "--count", "2"]);
+/// cli.command.call_fn(()).unwrap();
+/// ```
 pub use argcall_derive::Callable;
+/// The `CallableMut` macro derives [`CallableMut`](trait@CallableMut) with the same attributes as
+/// [`Callable`](derive@Callable). The fields are borrowed mutably, so each call can update them:
+///
+/// ```
+/// use argcall::CallableMut;
+///
+/// #[derive(CallableMut)]
+/// #[argcall(output = u32, args = (n: u32))]
+/// enum Accumulator {
+///     #[argcall(fn = { *total += n; *total })]
+///     Sum { total: u32 },
+///     #[argcall(fn = { *calls += 1; *calls })]
+///     Count { calls: u32 },
+/// }
+///
+/// let mut sum = Accumulator::Sum { total: 0 };
+/// assert_eq!(sum.call_fn_mut((2,)), 2);
+/// assert_eq!(sum.call_fn_mut((3,)), 5);
+/// ```
 pub use argcall_derive::CallableMut;
 pub use argcall_derive::CallableOnce;
 
+/// The `AsyncCallable` macro derives [`AsyncCallable`](trait@AsyncCallable) with the same attributes as
+/// [`Callable`](derive@Callable), except that each bound `fn` evaluates to a future.
+///
+/// The generated `Future` is a boxed `dyn Future`, so the futures must not borrow from the value being
+/// called. Copy or clone the fields they need instead.
+///
+/// ```
+/// use argcall::AsyncCallable;
+///
+/// #[derive(AsyncCallable)]
+/// #[argcall(output = i32)]
+/// enum Fetch {
+///     #[argcall(fn = fetch(*id))]
+///     ById { id: i32 },
+/// }
+///
+/// async fn fetch(id: i32) -> i32 {
+///     id * 10
+/// }
+///
+/// # pollster::block_on(async {
+/// assert_eq!(Fetch::ById { id: 4 }.call_fn_async(()).await, 40);
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub use argcall_derive::AsyncCallable;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cell::OnceCell;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
 #[cfg(feature = "async")]
 use core::future::{Future, Ready, ready};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(any(feature = "async", feature = "tokio"))]
+use core::pin::Pin;
+#[cfg(feature = "tokio")]
+use core::task::{Context, Poll};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Items referenced by the code generated by the derive macros.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::boxed::Box;
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "async")]
+    pub use core::future::Future;
+    #[cfg(feature = "async")]
+    pub use core::pin::Pin;
+    #[cfg(feature = "smallvec")]
+    pub use smallvec::SmallVec;
+    #[cfg(feature = "std")]
+    pub use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    /// Calls the inner field of a delegating tuple variant. The field type is checked against the
+    /// traits here rather than the callable traits themselves, so a field that is not callable is
+    /// reported as such instead of as a missing `Fn` impl
+    pub mod delegate {
+        #[cfg(feature = "async")]
+        use crate::AsyncCallable;
+        use crate::{Callable, CallableMut, CallableOnce, CallableRef, Tuple};
+
+        #[diagnostic::on_unimplemented(
+            message = "the inner field of a delegating tuple variant must implement `Callable<{Args}>`",
+            label = "`{Self}` does not implement `Callable<{Args}>`",
+            note = "bind a function with `#[argcall(fn = ...)]` to call the variant another way"
+        )]
+        pub trait DelegateCallable<Args: Tuple, Output> {
+            fn delegate(&self, args: Args) -> Output;
+        }
+
+        #[diagnostic::do_not_recommend]
+        impl<C: Callable<Args>, Args: Tuple> DelegateCallable<Args, C::Output> for C {
+            #[inline]
+            fn delegate(&self, args: Args) -> C::Output {
+                self.call_fn(args)
+            }
+        }
+
+        #[diagnostic::on_unimplemented(
+            message = "the inner field of a variant with `delegate = \"ref\"` is borrowed, so it must implement `Callable<{Args}>`",
+            label = "`{Self}` does not implement `Callable<{Args}>`",
+            note = "remove `delegate = \"ref\"` to pass the field by value"
+        )]
+        pub trait DelegateByRef<Args: Tuple, Output> {
+            fn delegate(&self, args: Args) -> Output;
+        }
+
+        #[diagnostic::do_not_recommend]
+        impl<C: Callable<Args>, Args: Tuple> DelegateByRef<Args, C::Output> for C {
+            #[inline]
+            fn delegate(&self, args: Args) -> C::Output {
+                self.call_fn(args)
+            }
+        }
+
+        #[diagnostic::on_unimplemented(
+            message = "the inner field of a delegating tuple variant must implement `CallableMut<{Args}>`",
+            label = "`{Self}` does not implement `CallableMut<{Args}>`",
+            note = "bind a function with `#[argcall(fn = ...)]` to call the variant another way"
+        )]
+        pub trait DelegateCallableMut<Args: Tuple, Output> {
+            fn delegate(&mut self, args: Args) -> Output;
+        }
+
+        #[diagnostic::do_not_recommend]
+        impl<C: CallableMut<Args>, Args: Tuple> DelegateCallableMut<Args, C::Output> for C {
+            #[inline]
+            fn delegate(&mut self, args: Args) -> C::Output {
+                self.call_fn_mut(args)
+            }
+        }
+
+        #[diagnostic::on_unimplemented(
+            message = "the inner field of a delegating tuple variant must implement `CallableOnce<{Args}>`",
+            label = "`{Self}` does not implement `CallableOnce<{Args}>`",
+            note = "bind a function with `#[argcall(fn = ...)]` to call the variant another way"
+        )]
+        pub trait DelegateCallableOnce<Args: Tuple, Output> {
+            fn delegate(self, args: Args) -> Output;
+        }
+
+        #[diagnostic::do_not_recommend]
+        impl<C: CallableOnce<Args>, Args: Tuple> DelegateCallableOnce<Args, C::Output> for C {
+            #[inline]
+            fn delegate(self, args: Args) -> C::Output {
+                self.call_fn_once(args)
+            }
+        }
+
+        #[diagnostic::on_unimplemented(
+            message = "the inner field of a delegating tuple variant must implement `CallableRef<{Args}>`",
+            label = "`{Self}` does not implement `CallableRef<{Args}>`",
+            note = "bind a function with `#[argcall(fn = ...)]` to call the variant another way"
+        )]
+        pub trait DelegateCallableRef<'a, Args: Tuple, Output> {
+            fn delegate(&'a self, args: Args) -> Output;
+        }
+
+        #[diagnostic::do_not_recommend]
+        impl<'a, C: CallableRef<'a, Args>, Args: Tuple> DelegateCallableRef<'a, Args, C::Output> for C {
+            #[inline]
+            fn delegate(&'a self, args: Args) -> C::Output {
+                self.call_fn_ref(args)
+            }
+        }
+
+        #[cfg(feature = "async")]
+        #[diagnostic::on_unimplemented(
+            message = "the inner field of a delegating tuple variant must implement `AsyncCallable<{Args}>`",
+            label = "`{Self}` does not implement `AsyncCallable<{Args}>`",
+            note = "bind a function with `#[argcall(fn = ...)]` to call the variant another way"
+        )]
+        pub trait DelegateAsyncCallable<Args: Tuple, Future> {
+            fn delegate(&self, args: Args) -> Future;
+        }
+
+        #[cfg(feature = "async")]
+        #[diagnostic::do_not_recommend]
+        impl<C: AsyncCallable<Args>, Args: Tuple> DelegateAsyncCallable<Args, C::Future> for C {
+            #[inline]
+            fn delegate(&self, args: Args) -> C::Future {
+                self.call_fn_async(args)
+            }
+        }
+
+        // The bounds are checked on the functions, where the messages above are reported; the
+        // output is a parameter of the traits so it is inferred without projecting through them
+
+        #[inline]
+        pub fn call_fn<C, Args, Output>(callable: &C, args: Args) -> Output
+        where
+            C: DelegateCallable<Args, Output>,
+            Args: Tuple,
+        {
+            callable.delegate(args)
+        }
+
+        #[inline]
+        pub fn call_fn_by_ref<C, Args, Output>(callable: &C, args: Args) -> Output
+        where
+            C: DelegateByRef<Args, Output>,
+            Args: Tuple,
+        {
+            callable.delegate(args)
+        }
+
+        #[inline]
+        pub fn call_fn_mut<C, Args, Output>(callable: &mut C, args: Args) -> Output
+        where
+            C: DelegateCallableMut<Args, Output>,
+            Args: Tuple,
+        {
+            callable.delegate(args)
+        }
+
+        #[inline]
+        pub fn call_fn_once<C, Args, Output>(callable: C, args: Args) -> Output
+        where
+            C: DelegateCallableOnce<Args, Output>,
+            Args: Tuple,
+        {
+            callable.delegate(args)
+        }
+
+        #[inline]
+        pub fn call_fn_ref<'a, C, Args, Output>(callable: &'a C, args: Args) -> Output
+        where
+            C: DelegateCallableRef<'a, Args, Output>,
+            Args: Tuple,
+        {
+            callable.delegate(args)
+        }
+
+        #[cfg(feature = "async")]
+        #[inline]
+        pub fn call_fn_async<C, Args, Future>(callable: &C, args: Args) -> Future
+        where
+            C: DelegateAsyncCallable<Args, Future>,
+            Args: Tuple,
+        {
+            callable.delegate(args)
+        }
+    }
+}
+
+/// Re-exports the traits along with their derive macros, so their methods and combinators such as
+/// [`Callable::map`] and [`CallableExt::call`] are in scope with a single import.
+///
+/// ```
+/// use argcall::prelude::*;
+///
+/// #[derive(Callable)]
+/// #[argcall(output = i32, args = (n: i32))]
+/// enum Op {
+///     #[argcall(fn = n + 1)]
+///     Increment,
+/// }
+///
+/// let c = Op::Increment.map(|x| x * 2).zip(|n: i32| n.to_string());
+/// assert_eq!(c.call_fn((20,)), (42, "20".to_string()));
+/// assert_eq!((|| 7).times(2).collect::<Vec<_>>(), [7, 7]);
+/// ```
+pub mod prelude {
+    #[cfg(feature = "async")]
+    pub use crate::{
+        AsyncCallable, AsyncCallableMut, AsyncCallableOnce, AsyncCallableSend, DynAsyncCallable,
+    };
+    pub use crate::{
+        Callable, CallableExt, CallableMut, CallableOnce, CallableRef, DynCallable, TryCallable,
+    };
+}
 
+/// Marker for the argument tuples accepted by the callable traits, implemented for tuples of up to 12 elements.
 pub trait Tuple {}
-impl Tuple for () {}
 
+/// Implements [`Tuple`] for a tuple of the given arity, and [`Callable`] for closures and functions
+/// taking the same number of arguments, so `|a, b| a + b` is a `Callable<(A, B)>`.
+macro_rules! impl_tuple {
+    ($($name:ident),*) => {
+        impl<$($name),*> Tuple for ($($name,)*) {}
+
+        impl<Func, Ret, $($name),*> Callable<($($name,)*)> for Func
+        where
+            Func: Fn($($name),*) -> Ret,
+        {
+            type Output = Ret;
+            #[inline]
+            #[allow(non_snake_case)]
+            fn call_fn(&self, ($($name,)*): ($($name,)*)) -> Self::Output {
+                self($($name),*)
+            }
+        }
+
+        // Implemented per tuple rather than for any `Args`, which would conflict with the blanket
+        // impls as other crates could implement `Callable<Args>` for these with their own `Args`
+        impl<Callee, Func, Ret, $($name),*> CallableMut<($($name,)*)> for MapMut<Callee, Func>
+        where
+            Callee: CallableMut<($($name,)*)>,
+            Func: FnMut(Callee::Output) -> Ret,
+        {
+            type Output = Ret;
+            #[inline]
+            fn call_fn_mut(&mut self, args: ($($name,)*)) -> Self::Output {
+                (self.f)(self.callable.call_fn_mut(args))
+            }
+        }
+
+        impl<'a, Callee, $($name),*> CallableMut<($($name,)*)> for ByMut<'a, Callee>
+        where
+            Callee: CallableMut<($($name,)*)> + ?Sized,
+        {
+            type Output = Callee::Output;
+            #[inline]
+            fn call_fn_mut(&mut self, args: ($($name,)*)) -> Self::Output {
+                self.0.call_fn_mut(args)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<Output, $($name),*> CallableMut<($($name,)*)> for BoxedCallableMut<($($name,)*), Output> {
+            type Output = Output;
+            fn call_fn_mut(&mut self, args: ($($name,)*)) -> Self::Output {
+                (self.inner)(args)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<Output, $($name),*> CallableOnce<($($name,)*)> for BoxedCallableOnce<($($name,)*), Output> {
+            type Output = Output;
+            fn call_fn_once(self, args: ($($name,)*)) -> Self::Output {
+                (self.inner)(args)
+            }
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+impl_tuple!(A, B, C, D, E, F, G);
+impl_tuple!(A, B, C, D, E, F, G, H);
+impl_tuple!(A, B, C, D, E, F, G, H, I);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not callable with arguments `{Args}`",
+    label = "expected a callable taking `{Args}`"
+)]
 pub trait Callable<Args: Tuple = ()> {
     type Output;
     fn call_fn(&self, args: Args) -> Self::Output;
@@ -37,6 +620,146 @@ pub trait Callable<Args: Tuple = ()> {
     fn call_fn_async(&self, args: Args) -> Ready<Self::Output> {
         ready(self.call_fn(args))
     }
+
+    /// Calls [`Callable::call_fn`] on the blocking threadpool of the current tokio runtime with
+    /// `tokio::task::spawn_blocking`, returning a future that resolves to its output, so a blocking
+    /// or CPU-heavy call does not hold up the executor. A panic in the call is resumed when the
+    /// future is polled.
+    ///
+    /// This requires the `tokio` feature and panics when called outside of a tokio runtime.
+    ///
+    /// ```
+    /// use argcall::Callable;
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// let sum = |n: u64| (0..=n).sum::<u64>();
+    /// let output = runtime.block_on(async { sum.call_fn_spawn_blocking((100,)).await });
+    /// assert_eq!(output, 5050);
+    /// ```
+    #[cfg(feature = "tokio")]
+    fn call_fn_spawn_blocking(self, args: Args) -> SpawnBlocking<Self::Output>
+    where
+        Self: Sized + Send + 'static,
+        Args: Send + 'static,
+        Self::Output: Send + 'static,
+    {
+        SpawnBlocking(tokio::task::spawn_blocking(move || self.call_fn(args)))
+    }
+
+    /// Returns a callable that transforms the output of this callable with `f`.
+    fn map<F, U>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> U,
+    {
+        Map { callable: self, f }
+    }
+
+    /// Returns a callable that passes the output of this callable to `next` as its only argument.
+    fn then<B>(self, next: B) -> Then<Self, B>
+    where
+        Self: Sized,
+        B: Callable<(Self::Output,)>,
+    {
+        Then { first: self, next }
+    }
+
+    /// Returns a callable that transforms the output of this callable with `f`, which may discard it
+    /// by returning `None`.
+    fn filter_map<F, U>(self, f: F) -> FilterMap<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> Option<U>,
+    {
+        FilterMap { callable: self, f }
+    }
+
+    /// Returns a callable that passes a reference to the output of this callable to `f` before
+    /// returning the output unchanged, which is handy for debugging.
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Output),
+    {
+        Inspect { callable: self, f }
+    }
+
+    /// Returns a callable that calls both this callable and `other` with the same arguments,
+    /// returning both outputs as a tuple.
+    fn zip<B>(self, other: B) -> Zip<Self, B>
+    where
+        Self: Sized,
+        B: Callable<Args>,
+    {
+        Zip {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Returns a callable that calls the callable returned by this callable with no arguments,
+    /// returning its output.
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+        Self::Output: Callable,
+    {
+        Flatten { callable: self }
+    }
+
+    /// Returns a callable that only calls this callable when `predicate` holds for the arguments,
+    /// returning `None` otherwise.
+    fn guard<P>(self, predicate: P) -> Guard<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&Args) -> bool,
+    {
+        Guard {
+            callable: self,
+            predicate,
+        }
+    }
+
+    /// Converts this callable into a closure taking the argument tuple.
+    ///
+    /// ```
+    /// use argcall::Callable;
+    ///
+    /// let f = (|a: i32, b: i32| a + b).into_fn();
+    /// assert_eq!(f((1, 2)), 3);
+    /// ```
+    fn into_fn(self) -> impl Fn(Args) -> Self::Output
+    where
+        Self: Sized,
+    {
+        move |args| self.call_fn(args)
+    }
+
+    /// Converts this callable into a boxed closure, so callables of different types can be
+    /// stored together.
+    #[cfg(feature = "alloc")]
+    fn into_boxed_fn<'a>(self) -> Box<dyn Fn(Args) -> Self::Output + 'a>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(move |args| self.call_fn(args))
+    }
+
+    /// Boxes this callable into a [`BoxedCallable`].
+    ///
+    /// ```
+    /// use argcall::Callable;
+    ///
+    /// let callables = vec![(|| 1).boxed(), (|| 2).boxed()];
+    /// assert_eq!(callables[1].call_fn(()), 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed(self) -> BoxedCallable<Args, Self::Output>
+    where
+        Self: Sized + 'static,
+    {
+        BoxedCallable::new(self)
+    }
 }
 
 pub trait CallableMut<Args: Tuple = ()> {
@@ -47,6 +770,33 @@ pub trait CallableMut<Args: Tuple = ()> {
     fn call_fn_async_mut(&mut self, args: Args) -> Ready<Self::Output> {
         ready(self.call_fn_mut(args))
     }
+
+    /// Returns a callable that transforms the output of this callable with `f`, where both this
+    /// callable and `f` may mutate their own state.
+    fn map_mut<F, U>(self, f: F) -> MapMut<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Output) -> U,
+    {
+        MapMut { callable: self, f }
+    }
+
+    /// Converts this callable into a closure taking the argument tuple.
+    fn into_fn_mut(mut self) -> impl FnMut(Args) -> Self::Output
+    where
+        Self: Sized,
+    {
+        move |args| self.call_fn_mut(args)
+    }
+
+    /// Boxes this callable into a [`BoxedCallableMut`].
+    #[cfg(feature = "alloc")]
+    fn boxed_mut(self) -> BoxedCallableMut<Args, Self::Output>
+    where
+        Self: Sized + 'static,
+    {
+        BoxedCallableMut::new(self)
+    }
 }
 
 pub trait CallableOnce<Args: Tuple = ()> {
@@ -60,6 +810,44 @@ pub trait CallableOnce<Args: Tuple = ()> {
     {
         ready(self.call_fn_once(args))
     }
+
+    /// Converts this callable into a closure taking the argument tuple.
+    fn into_fn_once(self) -> impl FnOnce(Args) -> Self::Output
+    where
+        Self: Sized,
+    {
+        move |args| self.call_fn_once(args)
+    }
+
+    /// Boxes this callable into a [`BoxedCallableOnce`].
+    #[cfg(feature = "alloc")]
+    fn boxed_once(self) -> BoxedCallableOnce<Args, Self::Output>
+    where
+        Self: Sized + 'static,
+    {
+        BoxedCallableOnce::new(self)
+    }
+}
+
+/// A callable whose output may borrow from `self` for the lifetime `'a`.
+///
+/// ```
+/// use argcall::CallableRef;
+///
+/// struct Name(String);
+///
+/// impl<'a> CallableRef<'a> for Name {
+///     type Output = &'a str;
+///     fn call_fn_ref(&'a self, _: ()) -> Self::Output {
+///         &self.0
+///     }
+/// }
+///
+/// assert_eq!(Name("argcall".to_string()).call_fn_ref(()), "argcall");
+/// ```
+pub trait CallableRef<'a, Args: Tuple = ()> {
+    type Output: 'a;
+    fn call_fn_ref(&'a self, args: Args) -> Self::Output;
 }
 
 impl<T, Args: Tuple> CallableMut<Args> for T
@@ -67,6 +855,7 @@ where
     T: Callable<Args>,
 {
     type Output = T::Output;
+    #[inline]
     fn call_fn_mut(&mut self, args: Args) -> Self::Output {
         self.call_fn(args)
     }
@@ -77,118 +866,1891 @@ where
     T: CallableMut<Args>,
 {
     type Output = T::Output;
+    #[inline]
     fn call_fn_once(mut self, args: Args) -> Self::Output {
         self.call_fn_mut(args)
     }
 }
 
-#[cfg(feature = "async")]
-/// An asynchronous callable trait.
+/// Shorthands for calling callables that take no arguments, implemented for every type.
 ///
-/// This trait is analogous to the synchronous `Callable` trait but returns a future.
-/// The associated type `Future` is any type implementing `Future`, and when resolved,
-/// will yield a value of type `Output`.
-pub trait AsyncCallable<Args: Tuple = ()> {
-    /// The output type produced by the asynchronous call.
-    type Output;
-    /// The future type that will eventually resolve to `Self::Output`.
-    type Future: Future<Output = Self::Output>;
+/// ```
+/// use argcall::{Callable, CallableExt};
+///
+/// struct One;
+///
+/// impl Callable for One {
+///     type Output = i32;
+///     fn call_fn(&self, _: ()) -> Self::Output {
+///         1
+///     }
+/// }
+///
+/// assert_eq!(One.call(), One.call_fn(()));
+/// ```
+pub trait CallableExt {
+    /// Calls [`Callable::call_fn`] with no arguments.
+    #[inline]
+    fn call(&self) -> <Self as Callable>::Output
+    where
+        Self: Callable,
+    {
+        self.call_fn(())
+    }
 
-    /// Asynchronously calls the bound function for the instance with the specified arguments,
-    /// returning a future that yields the result.
-    fn call_fn_async(&self, args: Args) -> Self::Future;
+    /// Calls [`CallableMut::call_fn_mut`] with no arguments.
+    #[inline]
+    fn call_mut(&mut self) -> <Self as CallableMut>::Output
+    where
+        Self: CallableMut,
+    {
+        self.call_fn_mut(())
+    }
+
+    /// Calls [`CallableOnce::call_fn_once`] with no arguments.
+    #[inline]
+    fn call_once(self) -> <Self as CallableOnce>::Output
+    where
+        Self: CallableOnce + Sized,
+    {
+        self.call_fn_once(())
+    }
+
+    /// Returns an iterator calling [`Callable::call_fn`] with no arguments `n` times, once for
+    /// each item. Unlike [`repeat`], nothing is called until the iterator is advanced.
+    ///
+    /// ```
+    /// use argcall::CallableExt;
+    ///
+    /// let c = || 7;
+    /// assert_eq!(c.times(3).collect::<Vec<_>>(), [7, 7, 7]);
+    /// ```
+    #[inline]
+    fn times(&self, n: usize) -> Times<'_, Self>
+    where
+        Self: Callable,
+    {
+        Times {
+            callable: self,
+            remaining: n,
+        }
+    }
+
+    /// Returns an iterator calling [`CallableMut::call_fn_mut`] with no arguments `n` times, once
+    /// for each item, so the state of the callable carries over from one item to the next.
+    ///
+    /// ```
+    /// use argcall::{CallableExt, CallableMut};
+    ///
+    /// #[derive(CallableMut)]
+    /// #[argcall(output = u32, fn = { *count += 1; *count })]
+    /// struct Counter {
+    ///     count: u32,
+    /// }
+    ///
+    /// let mut counter = Counter { count: 0 };
+    /// assert_eq!(counter.times_mut(3).collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn times_mut(&mut self, n: usize) -> TimesMut<'_, Self>
+    where
+        Self: CallableMut,
+    {
+        TimesMut {
+            callable: self,
+            remaining: n,
+        }
+    }
 }
 
-#[cfg(feature = "async")]
-/// A mutable asynchronous callable trait.
-///
-/// This is analogous to `CallableMut` and allows the method to be called on a mutable reference.
-pub trait AsyncCallableMut<Args: Tuple = ()> {
-    type Output;
-    type Future: Future<Output = Self::Output>;
+impl<T: ?Sized> CallableExt for T {}
 
-    /// Asynchronously calls the bound function using a mutable reference.
-    fn call_fn_async_mut(&mut self, args: Args) -> Self::Future;
+/// An iterator calling a callable a fixed number of times, created by [`CallableExt::times`].
+#[derive(Debug)]
+pub struct Times<'a, C: ?Sized> {
+    callable: &'a C,
+    remaining: usize,
 }
 
-#[cfg(feature = "async")]
-/// Provide a default implementation of `AsyncCallableMut` for any type that already implements `AsyncCallable`.
-impl<T, Args: Tuple> AsyncCallableMut<Args> for T
-where
-    T: AsyncCallable<Args>,
-{
-    type Output = T::Output;
-    type Future = T::Future;
+impl<C: Callable + ?Sized> Iterator for Times<'_, C> {
+    type Item = C::Output;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        Some(self.callable.call_fn(()))
+    }
 
-    fn call_fn_async_mut(&mut self, args: Args) -> Self::Future {
-        // Forward the call to the immutable version.
-        self.call_fn_async(args)
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
-#[cfg(feature = "async")]
-/// An asynchronous callable trait that consumes the instance.
-///
-/// This is analogous to `CallableOnce` and allows the call function to take ownership.
-pub trait AsyncCallableOnce<Args: Tuple = ()> {
-    type Output;
-    type Future: Future<Output = Self::Output>;
+impl<C: Callable + ?Sized> ExactSizeIterator for Times<'_, C> {}
+
+impl<C: ?Sized> Clone for Times<'_, C> {
+    fn clone(&self) -> Self {
+        Times {
+            callable: self.callable,
+            remaining: self.remaining,
+        }
+    }
+}
+
+/// An iterator calling a mutable callable a fixed number of times, created by
+/// [`CallableExt::times_mut`].
+#[derive(Debug)]
+pub struct TimesMut<'a, C: ?Sized> {
+    callable: &'a mut C,
+    remaining: usize,
+}
+
+impl<C: CallableMut + ?Sized> Iterator for TimesMut<'_, C> {
+    type Item = C::Output;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        Some(self.callable.call_fn_mut(()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<C: CallableMut + ?Sized> ExactSizeIterator for TimesMut<'_, C> {}
+
+/// A callable that transforms the output of another callable, created by [`Callable::map`].
+///
+/// ```
+/// use argcall::Callable;
+///
+/// let c = (|| 21).map(|x| (x * 2).to_string());
+/// assert_eq!(c.call_fn(()), "42");
+/// ```
+#[derive(Clone)]
+pub struct Map<C, F> {
+    callable: C,
+    f: F,
+}
+
+impl<C, F, Args: Tuple, U> Callable<Args> for Map<C, F>
+where
+    C: Callable<Args>,
+    F: Fn(C::Output) -> U,
+{
+    type Output = U;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        (self.f)(self.callable.call_fn(args))
+    }
+}
+
+/// A callable that transforms the output of a callable into an `Option`, created by
+/// [`Callable::filter_map`].
+///
+/// ```
+/// use argcall::Callable;
+///
+/// let half = (|x: i32| x).filter_map(|x| (x % 2 == 0).then_some(x / 2));
+/// assert_eq!(half.call_fn((42,)), Some(21));
+/// assert_eq!(half.call_fn((7,)), None);
+/// ```
+#[derive(Clone)]
+pub struct FilterMap<C, F> {
+    callable: C,
+    f: F,
+}
+
+impl<C, F, Args: Tuple, U> Callable<Args> for FilterMap<C, F>
+where
+    C: Callable<Args>,
+    F: Fn(C::Output) -> Option<U>,
+{
+    type Output = Option<U>;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        (self.f)(self.callable.call_fn(args))
+    }
+}
+
+/// A callable that runs a side effect on the output of another callable, created by
+/// [`Callable::inspect`].
+///
+/// ```
+/// use argcall::Callable;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let c = (|x: i32| x * 2).inspect(|_| calls.set(calls.get() + 1));
+/// assert_eq!(c.call_fn((21,)), 42);
+/// assert_eq!(calls.get(), 1);
+/// ```
+#[derive(Clone)]
+pub struct Inspect<C, F> {
+    callable: C,
+    f: F,
+}
+
+impl<C, F, Args: Tuple> Callable<Args> for Inspect<C, F>
+where
+    C: Callable<Args>,
+    F: Fn(&C::Output),
+{
+    type Output = C::Output;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        let output = self.callable.call_fn(args);
+        (self.f)(&output);
+        output
+    }
+}
+
+/// A callable that calls two callables with the same arguments, created by [`Callable::zip`]. The
+/// arguments are cloned for the first callable.
+///
+/// ```
+/// use argcall::Callable;
+///
+/// let c = (|x: i32| x * 2).zip(|x: i32| x.to_string());
+/// assert_eq!(c.call_fn((21,)), (42, "21".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Zip<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B, Args> Callable<Args> for Zip<A, B>
+where
+    Args: Tuple + Clone,
+    A: Callable<Args>,
+    B: Callable<Args>,
+{
+    type Output = (A::Output, B::Output);
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        (self.first.call_fn(args.clone()), self.second.call_fn(args))
+    }
+}
+
+/// A callable that calls the callable returned by another callable, created by
+/// [`Callable::flatten`].
+///
+/// ```
+/// use argcall::{Callable, Const};
+///
+/// let pick = (|x: i32| Const(x * 2)).flatten();
+/// assert_eq!(pick.call_fn((21,)), 42);
+/// ```
+#[derive(Clone)]
+pub struct Flatten<C> {
+    callable: C,
+}
+
+impl<C, Args: Tuple> Callable<Args> for Flatten<C>
+where
+    C: Callable<Args>,
+    C::Output: Callable,
+{
+    type Output = <C::Output as Callable>::Output;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        self.callable.call_fn(args).call_fn(())
+    }
+}
+
+/// A callable that calls another callable only when a predicate on the arguments holds, created by
+/// [`Callable::guard`]. The predicate borrows the arguments, so they are not cloned.
+///
+/// ```
+/// use argcall::Callable;
+///
+/// let half = (|x: i32| x / 2).guard(|&(x,): &(i32,)| x % 2 == 0);
+/// assert_eq!(half.call_fn((42,)), Some(21));
+/// assert_eq!(half.call_fn((7,)), None);
+/// ```
+#[derive(Clone)]
+pub struct Guard<C, P> {
+    callable: C,
+    predicate: P,
+}
+
+impl<C, P, Args: Tuple> Callable<Args> for Guard<C, P>
+where
+    C: Callable<Args>,
+    P: Fn(&Args) -> bool,
+{
+    type Output = Option<C::Output>;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        (self.predicate)(&args).then(|| self.callable.call_fn(args))
+    }
+}
+
+/// A callable that feeds the output of one callable into another, created by [`Callable::then`].
+///
+/// ```
+/// use argcall::Callable;
+///
+/// let c = (|x: i32| x * 2).then(|x: i32| x.to_string());
+/// assert_eq!(c.call_fn((21,)), "42");
+/// ```
+#[derive(Clone)]
+pub struct Then<A, B> {
+    first: A,
+    next: B,
+}
+
+impl<A, B, Args: Tuple> Callable<Args> for Then<A, B>
+where
+    A: Callable<Args>,
+    B: Callable<(A::Output,)>,
+{
+    type Output = B::Output;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        self.next.call_fn((self.first.call_fn(args),))
+    }
+}
+
+/// A callable that transforms the output of a mutable callable with an `FnMut`, created by
+/// [`CallableMut::map_mut`].
+#[derive(Clone)]
+pub struct MapMut<C, F> {
+    callable: C,
+    f: F,
+}
+
+/// A callable that returns a clone of its value.
+///
+/// ```
+/// use argcall::{Callable, Const};
+///
+/// let answer = Const(42).map(|x| x.to_string());
+/// assert_eq!(answer.call_fn(()), "42");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Const<T>(pub T);
+
+impl<T: Clone> Callable for Const<T> {
+    type Output = T;
+    #[inline]
+    fn call_fn(&self, _: ()) -> Self::Output {
+        self.0.clone()
+    }
+}
+
+/// A callable that returns its value by move, for values that cannot be cloned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConstOnce<T>(pub T);
+
+impl<T> CallableOnce for ConstOnce<T> {
+    type Output = T;
+    #[inline]
+    fn call_fn_once(self, _: ()) -> Self::Output {
+        self.0
+    }
+}
+
+/// A callable forwarding to a borrowed callable, for passing `&C` where a callable is taken by value.
+///
+/// `Callable` cannot be implemented for `&C` itself, as that would conflict with its impls for
+/// closures, which cover references to closures too.
+///
+/// ```
+/// use argcall::{ByRef, Callable};
+///
+/// fn use_it<X: Callable<Output = i32>>(x: X) -> i32 {
+///     x.call_fn(())
+/// }
+///
+/// let c = argcall::Const(42);
+/// assert_eq!(use_it(ByRef(&c)), 42);
+/// // `c` was only borrowed, so it can still be passed by value
+/// assert_eq!(use_it(c), 42);
+/// ```
+#[derive(Debug)]
+pub struct ByRef<'a, C: ?Sized>(pub &'a C);
+
+impl<C: ?Sized> Clone for ByRef<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized> Copy for ByRef<'_, C> {}
+
+impl<C: Callable<Args> + ?Sized, Args: Tuple> Callable<Args> for ByRef<'_, C> {
+    type Output = C::Output;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        self.0.call_fn(args)
+    }
+}
+
+/// A mutable callable forwarding to a mutably borrowed callable, for passing `&mut C` where a
+/// mutable callable is taken by value.
+///
+/// `CallableMut` cannot be implemented for `&mut C` itself, as that would conflict with its impl for
+/// every [`Callable`]. It is implemented for argument tuples of up to 12 elements.
+#[derive(Debug)]
+pub struct ByMut<'a, C: ?Sized>(pub &'a mut C);
+
+/// An optional callable returns `None` without calling anything when it is `None`.
+///
+/// ```
+/// use argcall::Callable;
+///
+/// let double = |x: i32| x * 2;
+/// assert_eq!(Some(double).call_fn((21,)), Some(42));
+/// assert_eq!(None::<fn(i32) -> i32>.call_fn((21,)), None);
+/// ```
+impl<C, Args: Tuple> Callable<Args> for Option<C>
+where
+    C: Callable<Args>,
+{
+    type Output = Option<C::Output>;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        self.as_ref().map(|callable| callable.call_fn(args))
+    }
+}
+
+/// A fallible callable returns a clone of its error without calling anything when it is `Err`.
+///
+/// ```
+/// use argcall::Callable;
+///
+/// let double = |x: i32| x * 2;
+/// assert_eq!(Ok::<_, String>(double).call_fn((21,)), Ok(42));
+/// ```
+impl<C, E, Args: Tuple> Callable<Args> for Result<C, E>
+where
+    C: Callable<Args>,
+    E: Clone,
+{
+    type Output = Result<C::Output, E>;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        match self {
+            Ok(callable) => Ok(callable.call_fn(args)),
+            Err(error) => Err(error.clone()),
+        }
+    }
+}
+
+/// A fallible callable, implemented for every [`Callable`] whose output is a `Result`.
+///
+/// This lets call sites propagate errors with `?` through `try_call`:
+///
+/// ```
+/// use argcall::{Callable, TryCallable};
+///
+/// #[derive(Callable)]
+/// #[argcall(try_output = u8, error = std::num::ParseIntError)]
+/// enum Parse {
+///     #[argcall(fn = "42".parse())]
+///     Answer,
+/// }
+///
+/// fn run() -> Result<u8, std::num::ParseIntError> {
+///     Ok(Parse::Answer.try_call(())? + 1)
+/// }
+///
+/// assert_eq!(run(), Ok(43));
+/// ```
+pub trait TryCallable<Args: Tuple = ()> {
+    type Ok;
+    type Error;
+    fn try_call(&self, args: Args) -> Result<Self::Ok, Self::Error>;
+
+    /// Returns a callable that calls `fallback` with the same arguments when this callable fails,
+    /// returning the error of `fallback` when both fail.
+    ///
+    /// ```
+    /// use argcall::{Callable, TryCallable};
+    ///
+    /// let primary = |s: &str| s.parse::<u8>();
+    /// let c = primary.or_else(|s: &str| s.trim().parse::<u8>());
+    /// assert_eq!(c.call_fn((" 42 ",)), Ok(42));
+    /// ```
+    fn or_else<B>(self, fallback: B) -> OrElse<Self, B>
+    where
+        Self: Sized,
+        B: TryCallable<Args, Ok = Self::Ok, Error = Self::Error>,
+    {
+        OrElse {
+            first: self,
+            fallback,
+        }
+    }
+}
+
+impl<C, Args: Tuple, T, E> TryCallable<Args> for C
+where
+    C: Callable<Args, Output = Result<T, E>>,
+{
+    type Ok = T;
+    type Error = E;
+    #[inline]
+    fn try_call(&self, args: Args) -> Result<Self::Ok, Self::Error> {
+        self.call_fn(args)
+    }
+}
+
+/// An object-safe companion to [`Callable`] that takes the output as a type parameter, so
+/// callables with the same output can be referenced as `&dyn DynCallable<Args, Output>`.
+///
+/// ```
+/// use argcall::DynCallable;
+///
+/// fn one() -> i32 {
+///     1
+/// }
+///
+/// let two = || 2;
+/// let callables: [&dyn DynCallable<(), i32>; 2] = [&one, &two];
+/// let outputs: Vec<i32> = callables.iter().map(|c| c.dyn_call(())).collect();
+/// assert_eq!(outputs, [1, 2]);
+/// ```
+pub trait DynCallable<Args: Tuple, Output> {
+    fn dyn_call(&self, args: Args) -> Output;
+}
+
+impl<C, Args: Tuple, Output> DynCallable<Args, Output> for C
+where
+    C: Callable<Args, Output = Output> + ?Sized,
+{
+    #[inline]
+    fn dyn_call(&self, args: Args) -> Output {
+        self.call_fn(args)
+    }
+}
+
+/// A callable that falls back to another callable when the first one fails, created by
+/// [`TryCallable::or_else`].
+#[derive(Clone)]
+pub struct OrElse<A, B> {
+    first: A,
+    fallback: B,
+}
+
+impl<A, B, Args> Callable<Args> for OrElse<A, B>
+where
+    Args: Tuple + Clone,
+    A: TryCallable<Args>,
+    B: TryCallable<Args, Ok = A::Ok, Error = A::Error>,
+{
+    type Output = Result<A::Ok, A::Error>;
+    #[inline]
+    fn call_fn(&self, args: Args) -> Self::Output {
+        self.first
+            .try_call(args.clone())
+            .or_else(|_| self.fallback.try_call(args))
+    }
+}
+
+/// A type-erased callable, for storing callables of different types with the same arguments and
+/// output together.
+///
+/// ```
+/// use argcall::{BoxedCallable, Callable};
+///
+/// fn one() -> i32 {
+///     1
+/// }
+///
+/// let callables: Vec<BoxedCallable<(), i32>> = vec![BoxedCallable::new(one), BoxedCallable::new(|| 2)];
+/// let outputs: Vec<i32> = callables.iter().map(|c| c.call_fn(())).collect();
+/// assert_eq!(outputs, [1, 2]);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct BoxedCallable<Args: Tuple, Output> {
+    inner: Box<dyn Fn(Args) -> Output>,
+}
+
+#[cfg(feature = "alloc")]
+impl<Args: Tuple, Output> BoxedCallable<Args, Output> {
+    pub fn new<C>(c: C) -> Self
+    where
+        C: Callable<Args, Output = Output> + 'static,
+    {
+        Self {
+            inner: Box::new(move |args| c.call_fn(args)),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Args: Tuple, Output> Callable<Args> for BoxedCallable<Args, Output> {
+    type Output = Output;
+    fn call_fn(&self, args: Args) -> Self::Output {
+        (self.inner)(args)
+    }
+}
+
+/// A type-erased [`CallableMut`], for storing callables with state of different types together.
+#[cfg(feature = "alloc")]
+pub struct BoxedCallableMut<Args: Tuple, Output> {
+    inner: Box<dyn FnMut(Args) -> Output>,
+}
+
+#[cfg(feature = "alloc")]
+impl<Args: Tuple, Output> BoxedCallableMut<Args, Output> {
+    pub fn new<C>(mut c: C) -> Self
+    where
+        C: CallableMut<Args, Output = Output> + 'static,
+    {
+        Self {
+            inner: Box::new(move |args| c.call_fn_mut(args)),
+        }
+    }
+}
+
+/// A type-erased [`CallableOnce`], for storing callables that are consumed when called together.
+#[cfg(feature = "alloc")]
+pub struct BoxedCallableOnce<Args: Tuple, Output> {
+    inner: Box<dyn FnOnce(Args) -> Output>,
+}
+
+#[cfg(feature = "alloc")]
+impl<Args: Tuple, Output> BoxedCallableOnce<Args, Output> {
+    pub fn new<C>(c: C) -> Self
+    where
+        C: CallableOnce<Args, Output = Output> + 'static,
+    {
+        Self {
+            inner: Box::new(move |args| c.call_fn_once(args)),
+        }
+    }
+}
+
+/// A registry of callables looked up by name at runtime, the dynamic counterpart of
+/// `#[argcall(dispatch)]` for plugin-style systems.
+///
+/// ```
+/// use argcall::{BoxedCallable, Dispatcher};
+///
+/// let mut dispatcher = Dispatcher::new();
+/// dispatcher.register("double", BoxedCallable::new(|x: i32| x * 2));
+/// dispatcher.register("square", BoxedCallable::new(|x: i32| x * x));
+/// assert_eq!(dispatcher.call("square", (3,)), Some(9));
+/// assert_eq!(dispatcher.call("cube", (3,)), None);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct Dispatcher<Args: Tuple, Output> {
+    callables: BTreeMap<String, BoxedCallable<Args, Output>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<Args: Tuple, Output> Dispatcher<Args, Output> {
+    pub fn new() -> Self {
+        Self {
+            callables: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `callable` under `name`, returning the callable it replaces, if any.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        callable: BoxedCallable<Args, Output>,
+    ) -> Option<BoxedCallable<Args, Output>> {
+        self.callables.insert(name.into(), callable)
+    }
+
+    /// Calls the callable registered under `name`, or returns `None` when there is none.
+    pub fn call(&self, name: &str, args: Args) -> Option<Output> {
+        self.callables
+            .get(name)
+            .map(|callable| callable.call_fn(args))
+    }
+
+    /// Returns the registered names in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.callables.keys().map(String::as_str)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Args: Tuple, Output> Default for Dispatcher<Args, Output> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A callable that calls its inner callable once and returns clones of the result afterwards.
+///
+/// ```
+/// use argcall::{Callable, Memoized};
+///
+/// let c = Memoized::new(|| "expensive".to_string());
+/// assert_eq!(c.call_fn(()), "expensive");
+/// assert_eq!(c.call_fn(()), "expensive");
+/// ```
+pub struct Memoized<C: Callable> {
+    callable: C,
+    value: OnceCell<C::Output>,
+}
+
+impl<C: Callable> Memoized<C> {
+    pub fn new(callable: C) -> Self {
+        Self {
+            callable,
+            value: OnceCell::new(),
+        }
+    }
+}
+
+impl<C> Callable for Memoized<C>
+where
+    C: Callable<Output: Clone>,
+{
+    type Output = C::Output;
+    fn call_fn(&self, args: ()) -> Self::Output {
+        self.value
+            .get_or_init(|| self.callable.call_fn(args))
+            .clone()
+    }
+}
+
+/// A callable that traces the calls to its inner callable with the `log` crate, logging its label
+/// at the trace level before each call and the `Debug` of the output at the debug level after it.
+///
+/// This requires the `log` feature.
+///
+/// ```
+/// use argcall::{Callable, Traced};
+///
+/// // Logs "double: calling" and "double: returned 42"
+/// let c = Traced::new(|x: i32| x * 2, "double");
+/// assert_eq!(c.call_fn((21,)), 42);
+/// ```
+#[cfg(feature = "log")]
+pub struct Traced<C> {
+    callable: C,
+    label: &'static str,
+}
+
+#[cfg(feature = "log")]
+impl<C> Traced<C> {
+    pub fn new(callable: C, label: &'static str) -> Self {
+        Self { callable, label }
+    }
+}
+
+#[cfg(feature = "log")]
+impl<C, Args: Tuple> Callable<Args> for Traced<C>
+where
+    C: Callable<Args, Output: core::fmt::Debug>,
+{
+    type Output = C::Output;
+    fn call_fn(&self, args: Args) -> Self::Output {
+        log::trace!("{}: calling", self.label);
+        let output = self.callable.call_fn(args);
+        log::debug!("{}: returned {:?}", self.label, output);
+        output
+    }
+}
+
+/// A callable that caches the result of its inner callable for each distinct argument tuple.
+///
+/// ```
+/// use argcall::{Callable, MemoizedArgs};
+///
+/// let square = MemoizedArgs::new(|x: u64| x * x);
+/// assert_eq!(square.call_fn((12,)), 144);
+/// assert_eq!(square.call_fn((12,)), 144);
+/// ```
+#[cfg(feature = "std")]
+pub struct MemoizedArgs<C: Callable<Args>, Args: Tuple> {
+    callable: C,
+    cache: RefCell<HashMap<Args, C::Output>>,
+}
+
+#[cfg(feature = "std")]
+impl<C: Callable<Args>, Args: Tuple> MemoizedArgs<C, Args> {
+    pub fn new(callable: C) -> Self {
+        Self {
+            callable,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, Args> Callable<Args> for MemoizedArgs<C, Args>
+where
+    C: Callable<Args, Output: Clone>,
+    Args: Tuple + Hash + Eq + Clone,
+{
+    type Output = C::Output;
+    fn call_fn(&self, args: Args) -> Self::Output {
+        if let Some(value) = self.cache.borrow().get(&args) {
+            return value.clone();
+        }
+        // The cache is not borrowed while calling, so the inner callable may call this one again
+        let value = self.callable.call_fn(args.clone());
+        self.cache.borrow_mut().insert(args, value.clone());
+        value
+    }
+}
+
+/// Calls `callable` with no arguments `n` times, collecting the outputs.
+///
+/// ```
+/// assert_eq!(argcall::repeat(&|| 7, 3), [7, 7, 7]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn repeat<C: Callable>(callable: &C, n: usize) -> Vec<C::Output> {
+    (0..n).map(|_| callable.call_fn(())).collect()
+}
+
+/// Calls `callable` with no arguments `n` times, collecting the outputs. The state of the callable
+/// carries over from one call to the next.
+#[cfg(feature = "alloc")]
+pub fn repeat_mut<C: CallableMut>(callable: &mut C, n: usize) -> Vec<C::Output> {
+    (0..n).map(|_| callable.call_fn_mut(())).collect()
+}
+
+/// Calls `callable` with no arguments until it returns [`ControlFlow::Break`], returning the value
+/// it breaks with. The values it continues with are discarded.
+///
+/// ```
+/// use argcall::CallableMut;
+/// use core::ops::ControlFlow;
+///
+/// #[derive(CallableMut)]
+/// #[argcall(output = ControlFlow<u32>, fn = {
+///     *count += 1;
+///     if *count == 3 { ControlFlow::Break(*count) } else { ControlFlow::Continue(()) }
+/// })]
+/// struct Counter {
+///     count: u32,
+/// }
+///
+/// assert_eq!(argcall::run_to_completion(&mut Counter { count: 0 }), 3);
+/// ```
+///
+/// [`ControlFlow::Break`]: core::ops::ControlFlow::Break
+pub fn run_to_completion<C, B, K>(callable: &mut C) -> B
+where
+    C: CallableMut<Output = core::ops::ControlFlow<B, K>>,
+{
+    loop {
+        if let core::ops::ControlFlow::Break(value) = callable.call_fn_mut(()) {
+            return value;
+        }
+    }
+}
+
+/// Deserializes a callable from a JSON value and calls it, so a tagged enum received as JSON can be
+/// dispatched directly. The deserialized fields are bound in the `fn` of the matching variant.
+///
+/// ```
+/// use argcall::Callable;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, Callable)]
+/// #[serde(tag = "op", rename_all = "lowercase")]
+/// #[argcall(output = i32)]
+/// enum Op {
+///     #[argcall(fn = value * 2)]
+///     Double { value: i32 },
+///     #[argcall(fn = -value)]
+///     Negate { value: i32 },
+/// }
+///
+/// let value = serde_json::json!({"op": "double", "value": 3});
+/// assert_eq!(argcall::call_from_value::<Op>(value).unwrap(), 6);
+/// ```
+#[cfg(feature = "serde")]
+pub fn call_from_value<T>(value: serde_json::Value) -> Result<T::Output, serde_json::Error>
+where
+    T: serde::de::DeserializeOwned + Callable,
+{
+    let callable: T = serde_json::from_value(value)?;
+    Ok(callable.call_fn(()))
+}
+
+#[cfg(feature = "async")]
+/// An asynchronous callable trait.
+///
+/// This trait is analogous to the synchronous `Callable` trait but returns a future.
+/// The associated type `Future` is any type implementing `Future`, and when resolved,
+/// will yield a value of type `Output`.
+pub trait AsyncCallable<Args: Tuple = ()> {
+    /// The output type produced by the asynchronous call.
+    type Output;
+    /// The future type that will eventually resolve to `Self::Output`.
+    type Future: Future<Output = Self::Output>;
+
+    /// Asynchronously calls the bound function for the instance with the specified arguments,
+    /// returning a future that yields the result.
+    fn call_fn_async(&self, args: Args) -> Self::Future;
+}
+
+/// A boxed future, as returned by [`DynAsyncCallable::call_fn_boxed`].
+#[cfg(feature = "async")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// A dyn-compatible asynchronous callable, which boxes the future so asynchronous callables of
+/// different types can be stored together.
+///
+/// It is implemented for every [`AsyncCallable`](trait@AsyncCallable) whose future is `'static`.
+///
+/// ```
+/// use argcall::{AsyncCallable, DynAsyncCallable};
+///
+/// #[derive(AsyncCallable)]
+/// #[argcall(output = i32)]
+/// enum Fetch {
+///     #[argcall(fn = async { 1 })]
+///     One,
+/// }
+///
+/// let callables: Vec<Box<dyn DynAsyncCallable<Output = i32>>> = vec![Box::new(Fetch::One)];
+/// # pollster::block_on(async {
+/// assert_eq!(callables[0].call_fn_boxed(()).await, 1);
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub trait DynAsyncCallable<Args: Tuple = ()> {
+    type Output;
+    fn call_fn_boxed(&self, args: Args) -> BoxFuture<'_, Self::Output>;
+}
+
+#[cfg(feature = "async")]
+impl<T, Args: Tuple> DynAsyncCallable<Args> for T
+where
+    T: AsyncCallable<Args>,
+    T::Future: 'static,
+{
+    type Output = T::Output;
+    fn call_fn_boxed(&self, args: Args) -> BoxFuture<'_, Self::Output> {
+        Box::pin(self.call_fn_async(args))
+    }
+}
+
+/// A boxed future that can be sent to another thread, as returned by
+/// [`AsyncCallableSend::call_fn_boxed_send`].
+#[cfg(feature = "async")]
+pub type BoxSendFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A dyn-compatible asynchronous callable whose boxed future is `Send`, so it can be spawned on a
+/// multithreaded executor.
+///
+/// It is implemented for every [`AsyncCallable`](trait@AsyncCallable) whose future is `Send` and
+/// `'static`.
+#[cfg(feature = "async")]
+pub trait AsyncCallableSend<Args: Tuple = ()> {
+    type Output;
+    fn call_fn_boxed_send(&self, args: Args) -> BoxSendFuture<'_, Self::Output>;
+}
+
+#[cfg(feature = "async")]
+impl<T, Args: Tuple> AsyncCallableSend<Args> for T
+where
+    T: AsyncCallable<Args>,
+    T::Future: Send + 'static,
+{
+    type Output = T::Output;
+    fn call_fn_boxed_send(&self, args: Args) -> BoxSendFuture<'_, Self::Output> {
+        Box::pin(self.call_fn_async(args))
+    }
+}
+
+#[cfg(feature = "async")]
+/// A mutable asynchronous callable trait.
+///
+/// This is analogous to `CallableMut` and allows the method to be called on a mutable reference.
+pub trait AsyncCallableMut<Args: Tuple = ()> {
+    type Output;
+    type Future: Future<Output = Self::Output>;
+
+    /// Asynchronously calls the bound function using a mutable reference.
+    fn call_fn_async_mut(&mut self, args: Args) -> Self::Future;
+}
+
+#[cfg(feature = "async")]
+/// Provide a default implementation of `AsyncCallableMut` for any type that already implements `AsyncCallable`.
+impl<T, Args: Tuple> AsyncCallableMut<Args> for T
+where
+    T: AsyncCallable<Args>,
+{
+    type Output = T::Output;
+    type Future = T::Future;
+
+    #[inline]
+    fn call_fn_async_mut(&mut self, args: Args) -> Self::Future {
+        // Forward the call to the immutable version.
+        self.call_fn_async(args)
+    }
+}
+
+#[cfg(feature = "async")]
+/// An asynchronous callable trait that consumes the instance.
+///
+/// This is analogous to `CallableOnce` and allows the call function to take ownership.
+pub trait AsyncCallableOnce<Args: Tuple = ()> {
+    type Output;
+    type Future: Future<Output = Self::Output>;
 
     /// Asynchronously calls the bound function, consuming the instance,
     /// and returns a future that yields the result.
     fn call_fn_async_once(self, args: Args) -> Self::Future;
 }
 
-#[cfg(feature = "async")]
-/// Provide a default implementation of `AsyncCallableOnce` for any type that already implements `AsyncCallableMut`.
-impl<T, Args: Tuple> AsyncCallableOnce<Args> for T
-where
-    T: AsyncCallableMut<Args>,
-{
-    type Output = T::Output;
-    type Future = T::Future;
+#[cfg(feature = "async")]
+/// Provide a default implementation of `AsyncCallableOnce` for any type that already implements `AsyncCallableMut`.
+impl<T, Args: Tuple> AsyncCallableOnce<Args> for T
+where
+    T: AsyncCallableMut<Args>,
+{
+    type Output = T::Output;
+    type Future = T::Future;
+
+    #[inline]
+    fn call_fn_async_once(mut self, args: Args) -> Self::Future {
+        // Forward the call to the mutable version.
+        self.call_fn_async_mut(args)
+    }
+}
+
+/// A future resolving to the output of a call made on the blocking threadpool of tokio, created by
+/// [`Callable::call_fn_spawn_blocking`].
+#[cfg(feature = "tokio")]
+pub struct SpawnBlocking<T>(tokio::task::JoinHandle<T>);
+
+#[cfg(feature = "tokio")]
+impl<T> core::future::Future for SpawnBlocking<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        core::future::Future::poll(Pin::new(&mut self.0), cx).map(|result| match result {
+            Ok(output) => output,
+            Err(error) => match error.try_into_panic() {
+                Ok(payload) => std::panic::resume_unwind(payload),
+                // The task is only cancelled when the runtime shuts down before it runs
+                Err(error) => panic!("{error}"),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "async")]
+    use pollster::FutureExt as _;
+
+    fn assert_send<T: Send>() {}
+    fn assert_sized<T: Sized>() {}
+
+    #[test]
+    fn test_unit_is_send() {
+        assert_send::<()>();
+    }
+
+    #[test]
+    fn test_tuple_unit_is_send() {
+        struct Wrapper<T: Tuple>(T);
+        assert_send::<Wrapper<()>>();
+    }
+
+    #[test]
+    fn test_callable() {
+        struct MyCallable;
+
+        impl Callable for MyCallable {
+            type Output = i32;
+            fn call_fn(&self, _: ()) -> Self::Output {
+                42
+            }
+        }
+
+        let callable = MyCallable;
+        assert_eq!(callable.call_fn(()), 42);
+        assert_sized::<MyCallable>();
+    }
+
+    #[test]
+    fn test_callable_with_args() {
+        struct Sum;
+
+        impl Callable<(i32, i32)> for Sum {
+            type Output = i32;
+            fn call_fn(&self, (a, b): (i32, i32)) -> Self::Output {
+                a + b
+            }
+        }
+
+        let mut c = Sum;
+        assert_eq!(c.call_fn((2, 3)), 5);
+        assert_eq!(c.call_fn_mut((4, 5)), 9);
+        assert_eq!(c.call_fn_once((6, 7)), 13);
+    }
+
+    #[test]
+    fn test_callable_with_mixed_args() {
+        struct Repeat;
+
+        impl Callable<(i32, String)> for Repeat {
+            type Output = String;
+            fn call_fn(&self, (n, s): (i32, String)) -> Self::Output {
+                s.repeat(n as usize)
+            }
+        }
+
+        assert_eq!(Repeat.call_fn((3, "ab".to_string())), "ababab");
+    }
+
+    #[test]
+    fn test_closures_and_functions() {
+        fn takes<C: Callable<Output = i32>>(c: C) -> i32 {
+            c.call_fn(())
+        }
+
+        fn takes_one<C: Callable<(i32,), Output = i32>>(c: C) -> i32 {
+            c.call_fn((20,))
+        }
+
+        fn forty_two() -> i32 {
+            42
+        }
+
+        fn add_one(x: i32) -> i32 {
+            x + 1
+        }
+
+        let offset = 2;
+        assert_eq!(takes(forty_two), 42);
+        assert_eq!(takes(|| 7), 7);
+        assert_eq!(takes(move || 40 + offset), 42);
+        assert_eq!(takes_one(add_one), 21);
+        assert_eq!(takes_one(|x| x * 2), 40);
+        assert_eq!((|a: i32, b: i32| a * b).call_fn((6, 7)), 42);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_boxed_callable() {
+        struct MyCallable;
+
+        impl Callable for MyCallable {
+            type Output = i32;
+            fn call_fn(&self, _: ()) -> Self::Output {
+                1
+            }
+        }
+
+        struct Scaled(i32);
+
+        impl Callable for Scaled {
+            type Output = i32;
+            fn call_fn(&self, _: ()) -> Self::Output {
+                self.0 * 10
+            }
+        }
+
+        fn three() -> i32 {
+            3
+        }
+
+        let callables: Vec<BoxedCallable<(), i32>> = vec![
+            BoxedCallable::new(MyCallable),
+            BoxedCallable::new(Scaled(2)),
+            BoxedCallable::new(three),
+            BoxedCallable::new(|| 4),
+        ];
+        let outputs: Vec<i32> = callables.iter().map(|c| c.call_fn(())).collect();
+        assert_eq!(outputs, vec![1, 20, 3, 4]);
+
+        let with_args: Vec<BoxedCallable<(i32, i32), i32>> = vec![
+            BoxedCallable::new(|a, b| a + b),
+            BoxedCallable::new(|a, b| a * b),
+        ];
+        let outputs: Vec<i32> = with_args.iter().map(|c| c.call_fn((3, 4))).collect();
+        assert_eq!(outputs, vec![7, 12]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_boxed() {
+        struct Counter(u32);
+
+        impl CallableMut for Counter {
+            type Output = u32;
+            fn call_fn_mut(&mut self, _: ()) -> Self::Output {
+                self.0 += 1;
+                self.0
+            }
+        }
+
+        struct Take(String);
+
+        impl CallableOnce for Take {
+            type Output = String;
+            fn call_fn_once(self, _: ()) -> Self::Output {
+                self.0
+            }
+        }
+
+        let scale = 10;
+        let mut callables = vec![(|| 1).boxed(), (move || 2 * scale).boxed()];
+        callables.push(Const(3).boxed());
+        let outputs: Vec<i32> = callables.iter().map(|c| c.call_fn(())).collect();
+        assert_eq!(outputs, vec![1, 20, 3]);
+
+        let mut counters = vec![Counter(0).boxed_mut(), Counter(10).boxed_mut()];
+        counters.push((|| 0).boxed_mut());
+        counters[0].call_fn_mut(());
+        let outputs: Vec<u32> = counters.iter_mut().map(|c| c.call_fn_mut(())).collect();
+        assert_eq!(outputs, vec![2, 11, 0]);
+
+        let takes = vec![
+            Take("a".to_string()).boxed_once(),
+            ConstOnce("b".to_string()).boxed_once(),
+        ];
+        let outputs: Vec<String> = takes.into_iter().map(|c| c.call_fn_once(())).collect();
+        assert_eq!(outputs, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_borrowed_args() {
+        struct Len;
+
+        impl Callable<(&str,)> for Len {
+            type Output = usize;
+            fn call_fn(&self, (s,): (&str,)) -> Self::Output {
+                s.len()
+            }
+        }
+
+        fn total<C: for<'a> Callable<(&'a str,), Output = usize>>(
+            c: &C,
+            words: &[String],
+        ) -> usize {
+            words.iter().map(|word| c.call_fn((word.as_str(),))).sum()
+        }
+
+        let local = String::from("borrowed");
+        assert_eq!(Len.call_fn((local.as_str(),)), 8);
+        assert_eq!(Len.call_fn_once((local.as_str(),)), 8);
+        let mut len = Len;
+        assert_eq!(len.call_fn_mut(("abc",)), 3);
+
+        let words = [String::from("ab"), String::from("cde")];
+        assert_eq!(total(&Len, &words), 5);
+        assert_eq!(total(&|s: &str| s.len(), &words), 5);
+
+        let first = |a: &str, b: &str| a.len().max(b.len());
+        assert_eq!(first.map(|n| n * 2).call_fn((&local, "x")), 16);
+    }
+
+    #[test]
+    fn test_run_to_completion() {
+        use core::ops::ControlFlow;
+
+        enum Machine {
+            Running { step: u32, limit: u32 },
+            Done,
+        }
+
+        impl CallableMut for Machine {
+            type Output = ControlFlow<&'static str, u32>;
+            fn call_fn_mut(&mut self, _: ()) -> Self::Output {
+                match self {
+                    Machine::Running { step, limit } if *step < *limit => {
+                        *step += 1;
+                        ControlFlow::Continue(*step)
+                    }
+                    Machine::Running { .. } => {
+                        *self = Machine::Done;
+                        ControlFlow::Continue(0)
+                    }
+                    Machine::Done => ControlFlow::Break("done"),
+                }
+            }
+        }
+
+        let mut machine = Machine::Running { step: 0, limit: 5 };
+        assert_eq!(run_to_completion(&mut machine), "done");
+        assert!(matches!(machine, Machine::Done));
+
+        let mut remaining = 4;
+        let mut calls = 0;
+        let mut countdown = (|| ()).map_mut(|()| {
+            calls += 1;
+            remaining -= 1;
+            match remaining {
+                0 => ControlFlow::Break(calls),
+                _ => ControlFlow::Continue(()),
+            }
+        });
+        assert_eq!(run_to_completion(&mut countdown), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_dispatcher() {
+        let mut dispatcher = Dispatcher::new();
+        assert!(
+            dispatcher
+                .register("one", BoxedCallable::new(|| 1))
+                .is_none()
+        );
+        assert!(dispatcher.register("two", Const(2).boxed()).is_none());
+        assert_eq!(dispatcher.call("one", ()), Some(1));
+        assert_eq!(dispatcher.call("two", ()), Some(2));
+        assert_eq!(dispatcher.call("three", ()), None);
+        assert_eq!(dispatcher.names().collect::<Vec<_>>(), ["one", "two"]);
+
+        let replaced = dispatcher.register("one", BoxedCallable::new(|| 10));
+        assert_eq!(replaced.map(|c| c.call_fn(())), Some(1));
+        assert_eq!(dispatcher.call("one", ()), Some(10));
+    }
 
-    fn call_fn_async_once(mut self, args: Args) -> Self::Future {
-        // Forward the call to the mutable version.
-        self.call_fn_async_mut(args)
+    #[test]
+    fn test_dyn_callable() {
+        struct Scaled(i32);
+
+        impl Callable for Scaled {
+            type Output = i32;
+            fn call_fn(&self, _: ()) -> Self::Output {
+                self.0 * 10
+            }
+        }
+
+        fn three() -> i32 {
+            3
+        }
+
+        let scaled = Scaled(2);
+        let four = || 4;
+        let callables: [&dyn DynCallable<(), i32>; 3] = [&scaled, &three, &four];
+        let outputs: Vec<i32> = callables.iter().map(|c| c.dyn_call(())).collect();
+        assert_eq!(outputs, vec![20, 3, 4]);
+
+        let add = |a: i32, b: i32| a + b;
+        let callable: &dyn DynCallable<(i32, i32), i32> = &add;
+        assert_eq!(callable.dyn_call((3, 4)), 7);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_map() {
+        struct MyCallable;
 
-    #[cfg(feature = "async")]
-    use pollster::FutureExt as _;
+        impl Callable for MyCallable {
+            type Output = i32;
+            fn call_fn(&self, _: ()) -> Self::Output {
+                42
+            }
+        }
 
-    fn assert_send<T: Send>() {}
-    fn assert_sized<T: Sized>() {}
+        let mapped = MyCallable.map(|x| format!("value: {}", x));
+        assert_eq!(mapped.call_fn(()), "value: 42");
+
+        let chained = MyCallable.map(|x| x + 1).map(|x| x.to_string());
+        assert_eq!(chained.call_fn(()), "43");
+
+        let with_args = (|a: i32, b: i32| a + b).map(|x| x.to_string());
+        assert_eq!(with_args.call_fn((1, 2)), "3");
+    }
 
     #[test]
-    fn test_unit_is_send() {
-        assert_send::<()>();
+    fn test_filter_map() {
+        let evens = (|x: i32| x).filter_map(|x| if x % 2 == 0 { Some(x) } else { None });
+        assert_eq!(evens.call_fn((4,)), Some(4));
+        assert_eq!(evens.call_fn((3,)), None);
+
+        let kept: Vec<i32> = (0..6).filter_map(|x| evens.call_fn((x,))).collect();
+        assert_eq!(kept, [0, 2, 4]);
+
+        let described = evens.map(|x| x.map(|x| x.to_string()));
+        assert_eq!(described.call_fn((8,)), Some("8".to_string()));
     }
 
     #[test]
-    fn test_tuple_unit_is_send() {
-        struct Wrapper<T: Tuple>(T);
-        assert_send::<Wrapper<()>>();
+    fn test_inspect() {
+        use core::cell::RefCell;
+
+        let seen = RefCell::new(Vec::new());
+        let c = (|x: i32| x * 2)
+            .inspect(|out| seen.borrow_mut().push(*out))
+            .map(|x| x + 1);
+        assert_eq!(c.call_fn((1,)), 3);
+        assert_eq!(c.call_fn((5,)), 11);
+        assert_eq!(seen.into_inner(), [2, 10]);
     }
 
     #[test]
-    fn test_callable() {
-        struct MyCallable;
+    fn test_option_and_result() {
+        let add = |a: i32, b: i32| a + b;
+        assert_eq!(Some(add).call_fn((1, 2)), Some(3));
+        assert_eq!(None::<fn(i32, i32) -> i32>.call_fn((1, 2)), None);
 
-        impl Callable for MyCallable {
+        let checked: Result<_, &str> = Ok(add);
+        assert_eq!(checked.call_fn((1, 2)), Ok(3));
+        assert_eq!(checked.try_call((3, 4)), Ok(7));
+        let failed: Result<fn(i32, i32) -> i32, &str> = Err("missing");
+        assert_eq!(failed.call_fn((1, 2)), Err("missing"));
+
+        let handlers = [Some(Const(1)), None, Some(Const(3))];
+        let outputs: Vec<Option<i32>> = handlers.iter().map(|h| h.call_fn(())).collect();
+        assert_eq!(outputs, [Some(1), None, Some(3)]);
+    }
+
+    #[test]
+    fn test_zip() {
+        struct Answer;
+
+        impl Callable for Answer {
             type Output = i32;
             fn call_fn(&self, _: ()) -> Self::Output {
                 42
             }
         }
 
-        let callable = MyCallable;
-        assert_eq!(callable.call_fn(()), 42);
-        assert_sized::<MyCallable>();
+        let pair = Answer.zip(|| "answer".to_string());
+        assert_eq!(pair.call_fn(()), (42, "answer".to_string()));
+
+        let owned = (|s: String| s.len()).zip(|s: String| s + "!");
+        assert_eq!(owned.call_fn(("hi".to_string(),)), (2, "hi!".to_string()));
+
+        let triple = Const(1)
+            .zip(Const(2))
+            .zip(Const(3))
+            .map(|((a, b), c)| a + b + c);
+        assert_eq!(triple.call_fn(()), 6);
+    }
+
+    #[test]
+    fn test_by_ref() {
+        fn use_it<X: Callable<Output = i32>>(x: X) -> i32 {
+            x.call_fn(())
+        }
+
+        fn use_mut<X: CallableMut<(u32,), Output = u32>>(mut x: X) -> u32 {
+            x.call_fn_mut((1,)) + x.call_fn_mut((1,))
+        }
+
+        struct Counter(u32);
+
+        impl CallableMut<(u32,)> for Counter {
+            type Output = u32;
+            fn call_fn_mut(&mut self, (step,): (u32,)) -> Self::Output {
+                self.0 += step;
+                self.0
+            }
+        }
+
+        let c = Const(42);
+        assert_eq!(use_it(ByRef(&c)), 42);
+        assert_eq!(use_it(ByRef(&c)), 42);
+        let dynamic: &dyn Callable<Output = i32> = &|| 7;
+        assert_eq!(use_it(ByRef(dynamic)), 7);
+
+        let mut counter = Counter(0);
+        assert_eq!(use_mut(ByMut(&mut counter)), 3);
+        assert_eq!(use_mut(ByMut(&mut counter)), 7);
+        assert_eq!(counter.0, 4);
+    }
+
+    #[test]
+    fn test_flatten() {
+        #[derive(Callable)]
+        #[argcall(crate = crate, output = String)]
+        enum Greeting {
+            #[argcall(fn = "hello".to_string())]
+            Hello,
+            #[argcall(fn = "bye".to_string())]
+            Bye,
+        }
+
+        let select = (|leaving: bool| match leaving {
+            true => Greeting::Bye,
+            false => Greeting::Hello,
+        })
+        .flatten();
+        assert_eq!(select.call_fn((false,)), "hello");
+        assert_eq!(select.call_fn((true,)), "bye");
+
+        let nested = (|| || 1).flatten().map(|x| x + 1);
+        assert_eq!(nested.call(), 2);
+    }
+
+    #[test]
+    fn test_guard() {
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let divide = (|a: i32, b: i32| {
+            calls.set(calls.get() + 1);
+            a / b
+        })
+        .guard(|&(_, b)| b != 0);
+        assert_eq!(divide.call_fn((6, 3)), Some(2));
+        assert_eq!(divide.call_fn((6, 0)), None);
+        assert_eq!(calls.get(), 1);
+
+        // The arguments are only borrowed by the predicate, so they need not be `Clone`
+        struct Token(u8);
+        let take = (|token: Token| token.0).guard(|(token,): &(Token,)| token.0 > 1);
+        assert_eq!(take.call_fn((Token(2),)), Some(2));
+        assert_eq!(take.call_fn((Token(1),)), None);
+    }
+
+    #[test]
+    fn test_const() {
+        assert_eq!(Const(7).call_fn(()), 7);
+        assert_eq!(Const(7).call(), 7);
+
+        let greeting = Const("hello".to_string());
+        assert_eq!(greeting.call_fn(()), "hello");
+        assert_eq!(greeting.call_fn(()), "hello");
+
+        let doubled = Const(7).map(|x| x * 2);
+        assert_eq!(doubled.call_fn(()), 14);
+        let shown = Const(7).then(|x: i32| x.to_string());
+        assert_eq!(shown.call_fn(()), "7");
+    }
+
+    #[test]
+    fn test_const_once() {
+        struct NotClone(i32);
+
+        let value = ConstOnce(NotClone(3)).call_fn_once(());
+        assert_eq!(value.0, 3);
+        assert_eq!(ConstOnce(vec![1, 2]).call_once(), [1, 2]);
+    }
+
+    #[test]
+    fn test_map_mut() {
+        struct Counter(i32);
+
+        impl CallableMut for Counter {
+            type Output = i32;
+            fn call_fn_mut(&mut self, _: ()) -> Self::Output {
+                self.0 += 1;
+                self.0
+            }
+        }
+
+        let mut calls = Vec::new();
+        {
+            let mut mapped = Counter(0).map_mut(|x| {
+                calls.push(x);
+                x.to_string()
+            });
+            assert_eq!(mapped.call_fn_mut(()), "1");
+            assert_eq!(mapped.call_fn_mut(()), "2");
+        }
+        assert_eq!(calls, vec![1, 2]);
+
+        let mut total = 0;
+        let mut sum = (|a: i32, b: i32| a + b).map_mut(|x| {
+            total += x;
+            total
+        });
+        assert_eq!(sum.call_fn_mut((1, 2)), 3);
+        assert_eq!(sum.call_fn_mut((3, 4)), 10);
+    }
+
+    #[test]
+    fn test_then() {
+        struct Double;
+
+        impl Callable<(i32,)> for Double {
+            type Output = i32;
+            fn call_fn(&self, (x,): (i32,)) -> Self::Output {
+                x * 2
+            }
+        }
+
+        let to_string = |x: i32| x.to_string();
+        let composed = Double.then(to_string);
+        assert_eq!(composed.call_fn((21,)), "42");
+
+        let twice = Double.then(Double).then(to_string);
+        assert_eq!(twice.call_fn((3,)), "12");
+
+        let from_unit = (|| 5).then(Double);
+        assert_eq!(from_unit.call_fn(()), 10);
+    }
+
+    #[test]
+    fn test_into_fn() {
+        struct Value(i32);
+
+        impl Callable for Value {
+            type Output = i32;
+            fn call_fn(&self, _: ()) -> Self::Output {
+                self.0
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            let fns: Vec<Box<dyn Fn(()) -> i32>> = vec![
+                Value(1).into_boxed_fn(),
+                (|| 2).into_boxed_fn(),
+                Value(3).map(|x| x * 10).into_boxed_fn(),
+            ];
+            let outputs: Vec<_> = fns.iter().map(|f| f(())).collect();
+            assert_eq!(outputs, vec![1, 2, 30]);
+        }
+
+        let f = Value(4).into_fn();
+        assert_eq!(f(()) + f(()), 8);
+
+        let mut add = Value(5).into_fn_mut();
+        assert_eq!(add(()), 5);
+
+        let once = Value(6).into_fn_once();
+        assert_eq!(once(()), 6);
+    }
+
+    #[test]
+    fn test_callable_ext() {
+        struct One;
+
+        impl Callable for One {
+            type Output = i32;
+            fn call_fn(&self, _: ()) -> Self::Output {
+                1
+            }
+        }
+
+        struct Counter(u32);
+
+        impl CallableMut for Counter {
+            type Output = u32;
+            fn call_fn_mut(&mut self, _: ()) -> Self::Output {
+                self.0 += 1;
+                self.0
+            }
+        }
+
+        assert_eq!(One.call(), One.call_fn(()));
+        assert_eq!(One.call_once(), 1);
+
+        let mut counter = Counter(0);
+        counter.call_mut();
+        assert_eq!(counter.call_mut(), 2);
+        assert_eq!(counter.call_once(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_repeat() {
+        struct Counter(u32);
+
+        impl CallableMut for Counter {
+            type Output = u32;
+            fn call_fn_mut(&mut self, _: ()) -> Self::Output {
+                self.0 += 1;
+                self.0
+            }
+        }
+
+        assert_eq!(repeat(&|| 7, 3), vec![7, 7, 7]);
+        assert_eq!(repeat(&|| 7, 0), Vec::<i32>::new());
+
+        let mut counter = Counter(0);
+        assert_eq!(repeat_mut(&mut counter, 3), vec![1, 2, 3]);
+        assert_eq!(repeat_mut(&mut counter, 2), vec![4, 5]);
+    }
+
+    #[test]
+    fn test_times() {
+        use core::cell::Cell;
+
+        struct Counter(u32);
+
+        impl CallableMut for Counter {
+            type Output = u32;
+            fn call_fn_mut(&mut self, _: ()) -> Self::Output {
+                self.0 += 1;
+                self.0
+            }
+        }
+
+        let seven = || 7;
+        assert!(seven.times(3).eq([7, 7, 7]));
+        assert_eq!(seven.times(0).next(), None);
+        assert_eq!(seven.times(4).len(), 4);
+
+        // Nothing is called before the iterator is advanced
+        let calls = Cell::new(0);
+        let counted = || calls.set(calls.get() + 1);
+        let mut times = counted.times(3);
+        assert_eq!(calls.get(), 0);
+        times.next();
+        assert_eq!(calls.get(), 1);
+        assert_eq!(times.count(), 2);
+        assert_eq!(calls.get(), 3);
+
+        let mut counter = Counter(0);
+        assert!(counter.times_mut(3).eq([1, 2, 3]));
+        assert!(counter.times_mut(2).eq([4, 5]));
+    }
+
+    #[test]
+    fn test_memoized() {
+        use core::cell::Cell;
+
+        struct Expensive<'a>(&'a Cell<u32>);
+
+        impl Callable for Expensive<'_> {
+            type Output = String;
+            fn call_fn(&self, _: ()) -> Self::Output {
+                self.0.set(self.0.get() + 1);
+                "result".to_string()
+            }
+        }
+
+        let calls = Cell::new(0);
+        let memoized = Memoized::new(Expensive(&calls));
+        assert_eq!(calls.get(), 0);
+        for _ in 0..3 {
+            assert_eq!(memoized.call_fn(()), "result");
+        }
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    #[cfg(all(feature = "log", feature = "std"))]
+    fn test_traced() {
+        std::thread_local! {
+            static LINES: RefCell<Vec<(log::Level, String)>> = const { RefCell::new(Vec::new()) };
+        }
+
+        /// Captures the records logged on the current thread, as the tests run in parallel
+        struct Capture;
+
+        impl log::Log for Capture {
+            fn enabled(&self, _: &log::Metadata<'_>) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record<'_>) {
+                LINES.with_borrow_mut(|lines| {
+                    lines.push((record.level(), record.args().to_string()))
+                });
+            }
+
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&Capture).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let add = Traced::new(|a: i32, b: i32| a + b, "add");
+        assert_eq!(add.call_fn((1, 2)), 3);
+        assert_eq!(add.call_fn((3, 4)), 7);
+        assert_eq!(
+            LINES.take(),
+            [
+                (log::Level::Trace, "add: calling".to_string()),
+                (log::Level::Debug, "add: returned 3".to_string()),
+                (log::Level::Trace, "add: calling".to_string()),
+                (log::Level::Debug, "add: returned 7".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_memoized_args() {
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let add = MemoizedArgs::new(|a: i32, b: i32| {
+            calls.set(calls.get() + 1);
+            a + b
+        });
+        assert_eq!(add.call_fn((1, 2)), 3);
+        assert_eq!(add.call_fn((1, 2)), 3);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(add.call_fn((2, 1)), 3);
+        assert_eq!(add.call_fn((2, 1)), 3);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_call_from_value() {
+        #[derive(serde::Deserialize)]
+        #[serde(tag = "op", rename_all = "lowercase")]
+        enum Op {
+            Double { value: i32 },
+            Add { value: i32, other: i32 },
+        }
+
+        impl Callable for Op {
+            type Output = i32;
+            fn call_fn(&self, _: ()) -> Self::Output {
+                match self {
+                    Op::Double { value } => value * 2,
+                    Op::Add { value, other } => value + other,
+                }
+            }
+        }
+
+        let double = serde_json::json!({"op": "double", "value": 3});
+        assert_eq!(call_from_value::<Op>(double).unwrap(), 6);
+        let add = serde_json::json!({"op": "add", "value": 3, "other": 4});
+        assert_eq!(call_from_value::<Op>(add).unwrap(), 7);
+        let unknown = serde_json::json!({"op": "unknown"});
+        assert!(call_from_value::<Op>(unknown).is_err());
+    }
+
+    #[test]
+    fn test_try_callable() {
+        struct Checked(i32);
+
+        impl Callable<(i32,)> for Checked {
+            type Output = Result<i32, String>;
+            fn call_fn(&self, (divisor,): (i32,)) -> Self::Output {
+                self.0
+                    .checked_div(divisor)
+                    .ok_or_else(|| "division by zero".to_string())
+            }
+        }
+
+        fn halve(c: &Checked) -> Result<i32, String> {
+            let half = c.try_call((2,))?;
+            Ok(half)
+        }
+
+        fn by_zero(c: &Checked) -> Result<i32, String> {
+            let value = c.try_call((0,))?;
+            Ok(value + 1)
+        }
+
+        let c = Checked(10);
+        assert_eq!(halve(&c), Ok(5));
+        assert_eq!(by_zero(&c), Err("division by zero".to_string()));
+    }
+
+    #[test]
+    fn test_or_else() {
+        let primary = |key: &str| match key {
+            "a" => Ok(1),
+            _ => Err(format!("{} not in primary", key)),
+        };
+        let secondary = |key: &str| match key {
+            "b" => Ok(2),
+            _ => Err(format!("{} not in secondary", key)),
+        };
+        let c = primary.or_else(secondary);
+        assert_eq!(c.call_fn(("a",)), Ok(1));
+        assert_eq!(c.try_call(("b",)), Ok(2));
+        assert_eq!(c.call_fn(("c",)), Err("c not in secondary".to_string()));
     }
 
     #[cfg(feature = "async")]
@@ -211,4 +2773,105 @@ mod tests {
             42
         );
     }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_dyn_async_callable() {
+        struct Ready42;
+
+        impl AsyncCallable for Ready42 {
+            type Output = i32;
+            type Future = Ready<Self::Output>;
+
+            fn call_fn_async(&self, _: ()) -> Self::Future {
+                ready(42)
+            }
+        }
+
+        struct Doubled(i32);
+
+        impl AsyncCallable for Doubled {
+            type Output = i32;
+            type Future = BoxFuture<'static, i32>;
+
+            fn call_fn_async(&self, _: ()) -> Self::Future {
+                let value = self.0;
+                Box::pin(async move { value * 2 })
+            }
+        }
+
+        let callables: Vec<Box<dyn DynAsyncCallable<Output = i32>>> =
+            vec![Box::new(Ready42), Box::new(Doubled(4))];
+        let outputs: Vec<i32> = callables
+            .iter()
+            .map(|c| c.call_fn_boxed(()).block_on())
+            .collect();
+        assert_eq!(outputs, vec![42, 8]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_call_fn_spawn_blocking() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let caller = std::thread::current().id();
+        let output = runtime.block_on(async {
+            (|n: u64| (n * 2, std::thread::current().id()))
+                .call_fn_spawn_blocking((21,))
+                .await
+        });
+        assert_eq!(output.0, 42);
+        assert_ne!(output.1, caller);
+
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            runtime.block_on(async {
+                (|| -> i32 { panic!("blocking") })
+                    .call_fn_spawn_blocking(())
+                    .await
+            })
+        }));
+        assert!(panicked.is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_callable_send() {
+        struct Doubled(i32);
+
+        impl AsyncCallable for Doubled {
+            type Output = i32;
+            type Future = BoxSendFuture<'static, i32>;
+
+            fn call_fn_async(&self, _: ()) -> Self::Future {
+                let value = self.0;
+                Box::pin(async move { value * 2 })
+            }
+        }
+
+        struct Ready42;
+
+        impl AsyncCallable for Ready42 {
+            type Output = i32;
+            type Future = Ready<Self::Output>;
+
+            fn call_fn_async(&self, _: ()) -> Self::Future {
+                ready(42)
+            }
+        }
+
+        let callables: Vec<Box<dyn AsyncCallableSend<Output = i32>>> =
+            vec![Box::new(Doubled(4)), Box::new(Ready42)];
+        let outputs: Vec<i32> = std::thread::scope(|s| {
+            let handles: Vec<_> = callables
+                .iter()
+                .map(|c| {
+                    let future = c.call_fn_boxed_send(());
+                    s.spawn(move || future.block_on())
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert_eq!(outputs, vec![8, 42]);
+    }
 }
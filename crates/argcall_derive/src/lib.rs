@@ -1,30 +1,222 @@
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Attribute, Data, DeriveInput, Fields, Ident, LitStr, Variant, parse_macro_input};
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Expr, Fields, GenericParam, Generics,
+    Ident, Lifetime, LifetimeParam, LitStr, Meta, Pat, Token, Type, Variant, Visibility,
+    WherePredicate, parenthesized, parse_macro_input,
+};
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Clone)]
 enum CallableType {
     Callable,
     CallableMut,
     CallableOnce,
+    AsyncCallable,
+    /// `CallableRef` with the lifetime of the borrow of `self`
+    CallableRef(Lifetime),
 }
 
 impl CallableType {
-    fn as_trait(&self) -> TokenStream {
+    fn as_trait(&self, krate: &syn::Path) -> TokenStream {
         match self {
-            CallableType::Callable => quote! { argcall::Callable },
-            CallableType::CallableMut => quote! { argcall::CallableMut },
-            CallableType::CallableOnce => quote! { argcall::CallableOnce },
+            CallableType::Callable => quote! { #krate::Callable },
+            CallableType::CallableMut => quote! { #krate::CallableMut },
+            CallableType::CallableOnce => quote! { #krate::CallableOnce },
+            CallableType::AsyncCallable => quote! { #krate::AsyncCallable },
+            CallableType::CallableRef(_) => quote! { #krate::CallableRef },
         }
     }
 
-    fn as_fn(&self) -> TokenStream {
+    /// The trait with its generic arguments, as it appears in the generated impls
+    fn as_impl_trait(&self, krate: &syn::Path, args_type: &TokenStream) -> TokenStream {
+        let trait_name = self.as_trait(krate);
         match self {
-            CallableType::Callable => quote! { call_fn(&self, _: ()) },
-            CallableType::CallableMut => quote! { call_fn_mut(&mut self, _: ()) },
-            CallableType::CallableOnce => quote! { call_fn_once(self, _: ()) },
+            CallableType::CallableRef(lifetime) => quote! { #trait_name<#lifetime, #args_type> },
+            _ => quote! { #trait_name<#args_type> },
         }
     }
+
+    fn as_method(&self) -> TokenStream {
+        match self {
+            CallableType::Callable => quote! { call_fn },
+            CallableType::CallableMut => quote! { call_fn_mut },
+            CallableType::CallableOnce => quote! { call_fn_once },
+            CallableType::AsyncCallable => quote! { call_fn_async },
+            CallableType::CallableRef(_) => quote! { call_fn_ref },
+        }
+    }
+
+    fn as_fn(&self, args: &CallArgs) -> TokenStream {
+        let method = self.as_method();
+        let receiver = match self {
+            CallableType::Callable | CallableType::AsyncCallable => quote! { &self },
+            CallableType::CallableMut => quote! { &mut self },
+            CallableType::CallableOnce => quote! { self },
+            CallableType::CallableRef(lifetime) => quote! { &#lifetime self },
+        };
+        let args_ident = CallArgs::ident();
+        let args_type = args.as_type();
+        quote! { #method(#receiver, #args_ident: #args_type) }
+    }
+
+    /// The associated types of the impl; async impls return a boxed future
+    fn as_associated_types(&self, krate: &syn::Path, output_type: &TokenStream) -> TokenStream {
+        match self {
+            CallableType::AsyncCallable => quote! {
+                type Output = #output_type;
+                type Future = #krate::__private::Pin<
+                    #krate::__private::Box<dyn #krate::__private::Future<Output = #output_type>>,
+                >;
+            },
+            _ => quote! { type Output = #output_type; },
+        }
+    }
+
+    fn as_return_type(&self, output_type: &TokenStream) -> TokenStream {
+        match self {
+            CallableType::AsyncCallable => quote! { Self::Future },
+            _ => output_type.clone(),
+        }
+    }
+
+    /// The value matched on in the generated method, dereferenced for the borrowing receivers
+    fn as_scrutinee(&self) -> TokenStream {
+        match self {
+            CallableType::CallableOnce => quote! { self },
+            _ => quote! { *self },
+        }
+    }
+
+    /// The binding mode of the fields, so they are borrowed the same way as the receiver. Moved
+    /// fields are mutable, so consuming functions can modify them first
+    fn as_binding_mode(&self) -> TokenStream {
+        match self {
+            CallableType::CallableMut => quote! { ref mut },
+            CallableType::CallableOnce => quote! { mut },
+            _ => quote! { ref },
+        }
+    }
+
+    /// Passes `value` the same way as the receiver of the method
+    fn as_receiver_value(&self, value: TokenStream) -> TokenStream {
+        match self {
+            CallableType::CallableMut => quote! { (&mut #value) },
+            CallableType::CallableOnce => quote! { (#value) },
+            _ => quote! { (&#value) },
+        }
+    }
+
+    /// Wraps the value of a match arm into the return type of the method
+    fn wrap(&self, krate: &syn::Path, body: TokenStream) -> TokenStream {
+        match self {
+            CallableType::AsyncCallable => quote! { #krate::__private::Box::pin(#body) },
+            _ => body,
+        }
+    }
+
+    /// Switches `Callable` to `CallableRef` when the `ref` attribute is given
+    fn with_ref(self, lifetime: Option<Lifetime>) -> Result<Self, syn::Error> {
+        match (self, lifetime) {
+            (CallableType::Callable, Some(lifetime)) => Ok(CallableType::CallableRef(lifetime)),
+            (_, Some(lifetime)) => Err(syn::Error::new_spanned(
+                lifetime,
+                "'ref' is only supported by #[derive(Callable)]",
+            )),
+            (callable_type, None) => Ok(callable_type),
+        }
+    }
+}
+
+/// The call-time arguments declared with `#[argcall(args = (pattern: Type, ...))]`
+#[derive(Default)]
+struct CallArgs {
+    patterns: Vec<Pat>,
+    types: Vec<Type>,
+}
+
+impl CallArgs {
+    /// The identifier of the argument tuple in generated methods, hidden from user expressions
+    fn ident() -> Ident {
+        Ident::new("args", Span::mixed_site())
+    }
+
+    fn as_type(&self) -> TokenStream {
+        let types = &self.types;
+        quote! { (#(#types,)*) }
+    }
+
+    /// Destructures the argument tuple into the declared patterns
+    fn bind(&self) -> TokenStream {
+        let patterns = &self.patterns;
+        let args_ident = Self::ident();
+        quote! { let (#(#patterns,)*) = #args_ident; }
+    }
+
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let content;
+        parenthesized!(content in input);
+        let mut args = CallArgs::default();
+        for arg in content.parse_terminated(
+            |input| {
+                let pattern = Pat::parse_single(input)?;
+                check_irrefutable(&pattern)?;
+                input.parse::<Token![:]>()?;
+                let ty: Type = input.parse()?;
+                Ok((pattern, ty))
+            },
+            Token![,],
+        )? {
+            args.patterns.push(arg.0);
+            args.types.push(arg.1);
+        }
+        Ok(args)
+    }
+}
+
+/// Rejects `fn_traits` on the callables without a matching `Fn` trait
+fn check_fn_traits(
+    callable_type: &CallableType,
+    fn_traits: Option<Span>,
+) -> Result<(), syn::Error> {
+    match (fn_traits, callable_type) {
+        (Some(span), CallableType::AsyncCallable | CallableType::CallableRef(_)) => {
+            Err(syn::Error::new(
+                span,
+                "'fn_traits' is only supported by #[derive(Callable)] without 'ref', #[derive(CallableMut)] and #[derive(CallableOnce)]",
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Rejects the patterns that can only match some values, as the arguments are bound with `let`
+fn check_irrefutable(pattern: &Pat) -> Result<(), syn::Error> {
+    match pattern {
+        Pat::Lit(_) | Pat::Range(_) | Pat::Or(_) => Err(syn::Error::new_spanned(
+            pattern,
+            "argument patterns must be irrefutable",
+        )),
+        Pat::Ident(pat) => match &pat.subpat {
+            Some((_, subpat)) => check_irrefutable(subpat),
+            None => Ok(()),
+        },
+        Pat::Tuple(pat) => pat.elems.iter().try_for_each(check_irrefutable),
+        Pat::TupleStruct(pat) => pat.elems.iter().try_for_each(check_irrefutable),
+        Pat::Struct(pat) => pat
+            .fields
+            .iter()
+            .try_for_each(|field| check_irrefutable(&field.pat)),
+        Pat::Slice(pat) => pat.elems.iter().try_for_each(check_irrefutable),
+        Pat::Paren(pat) => check_irrefutable(&pat.pat),
+        Pat::Reference(pat) => check_irrefutable(&pat.pat),
+        Pat::Type(pat) => check_irrefutable(&pat.pat),
+        _ => Ok(()),
+    }
 }
 
 /// A procedural macro to derive the Callable trait
@@ -32,208 +224,1949 @@ impl CallableType {
 pub fn callable_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     generic_callable(CallableType::Callable, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 #[proc_macro_derive(CallableMut, attributes(argcall))]
 pub fn callable_mut_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     generic_callable(CallableType::CallableMut, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 #[proc_macro_derive(CallableOnce, attributes(argcall))]
 pub fn callable_once_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     generic_callable(CallableType::CallableOnce, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-fn generic_callable(callable_type: CallableType, input: DeriveInput) -> proc_macro::TokenStream {
-    // Parse the input token stream as a DeriveInput struct
+/// Derives `AsyncCallable`, where each bound `fn` evaluates to a future
+#[proc_macro_derive(AsyncCallable, attributes(argcall))]
+pub fn async_callable_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    generic_callable(CallableType::AsyncCallable, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn generic_callable(
+    callable_type: CallableType,
+    input: DeriveInput,
+) -> Result<TokenStream, syn::Error> {
+    match &input.data {
+        Data::Enum(data) => callable_enum(callable_type, &input, data),
+        Data::Struct(data) => callable_struct(callable_type, &input, data),
+        Data::Union(data) => {
+            let union_token = data.union_token;
+            let ident = &input.ident;
+            Err(syn::Error::new_spanned(
+                quote! { #union_token #ident },
+                "#[derive(Callable)] can only be applied to enums and structs",
+            ))
+        }
+    }
+}
 
+fn callable_enum(
+    callable_type: CallableType,
+    input: &DeriveInput,
+    data: &DataEnum,
+) -> Result<TokenStream, syn::Error> {
     // Get the enum name
-    let enum_name = input.ident;
+    let enum_name = &input.ident;
 
-    // Extract the data of the enum (expecting variants)
-    let data = match input.data {
-        Data::Enum(data) => data,
-        _ => panic!("#[derive(Callable)] can only be applied to enums"),
+    let attrs = parse_container_attributes(&input.attrs, std::iter::empty())?;
+    if data.variants.is_empty() && attrs.allow_empty.is_none() {
+        return Err(syn::Error::new_spanned(
+            enum_name,
+            "an enum without variants can never be called, \
+             add #[argcall(allow_empty)] if this is intended",
+        ));
+    }
+    let krate = &attrs.krate;
+    let callable_type = callable_type.with_ref(attrs.ref_lifetime.clone())?;
+    // The attributes of each variant, parsed once for all of the generated items
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let names = field_bindings(&variant.fields);
+            parse_variant_attributes(&variant.attrs, names.into_iter())
+        })
+        .collect::<Result<Vec<_>, syn::Error>>()?;
+    let output_type = match (attrs.output, &attrs.enum_output) {
+        (Some(output), Some(_)) => {
+            return Err(syn::Error::new_spanned(
+                output,
+                "'output' cannot be combined with 'enum_output', the output is the generated enum",
+            ));
+        }
+        (Some(output), None) => output,
+        (None, Some(_)) => format_ident!("{}Output", enum_name).into_token_stream(),
+        // Without a delegating variant to infer it from, the variants are called for their side
+        // effects only
+        (None, None) => infer_output(&callable_type, krate, &attrs.args, data, &variants)
+            .unwrap_or_else(|| quote! { () }),
     };
 
-    let output_type = input
-        .attrs
-        .iter()
-        .filter(|attr| attr.path().is_ident("argcall"))
-        .map(parse_output_attribute)
-        .next()
-        .expect("Expected #[argcall(output=...)] attribute on enum")
-        .unwrap();
+    // Binding an `async_fn` on any variant of a `Callable` enum makes it an `AsyncCallable`
+    let has_async_fn = variants.iter().any(|variant| variant.async_fn.is_some());
+    let (callable_type, ready_sync) = match callable_type {
+        CallableType::Callable if has_async_fn => (CallableType::AsyncCallable, true),
+        callable_type => (callable_type, false),
+    };
+    check_fn_traits(&callable_type, attrs.fn_traits)?;
 
+    let struct_suffix = attrs
+        .struct_suffix
+        .as_ref()
+        .map_or_else(|| "Callable".to_string(), LitStr::value);
+    check_wrapped_body(
+        "catch_unwind",
+        &callable_type,
+        attrs.catch_unwind,
+        attrs.const_eval,
+    )?;
+    check_wrapped_body(
+        "box_output",
+        &callable_type,
+        attrs.box_output,
+        attrs.const_eval,
+    )?;
+    if let Some((span, _)) = &attrs.enum_output {
+        check_wrapped_body("enum_output", &callable_type, Some(*span), None)?;
+        if attrs.box_output.is_some() {
+            return Err(syn::Error::new(
+                *span,
+                "'enum_output' cannot be combined with 'box_output'",
+            ));
+        }
+    }
+    let generics = impl_generics(&callable_type, &input.generics, &attrs.bound);
+    let context = EnumContext {
+        callable_type: &callable_type,
+        ready_sync,
+        catch_unwind: attrs.catch_unwind.is_some(),
+        box_output: attrs.box_output.is_some(),
+        enum_output: attrs.enum_output.is_some(),
+        fallback: attrs.func.as_ref(),
+        krate,
+        input,
+        data,
+        variants,
+        generics: &generics,
+        output_type: &output_type,
+        args: &attrs.args,
+        struct_vis: &attrs.struct_vis,
+        struct_suffix: &struct_suffix,
+        fn_traits: attrs.fn_traits.is_some(),
+        struct_derive: &attrs.struct_derive,
+    };
+
+    let mut struct_names: Vec<Ident> = Vec::new();
     let mut variant_structs = Vec::new();
     let mut match_arms = Vec::new();
 
-    data.variants
-        .iter()
-        .try_for_each(|variant| {
-            let (variant_struct, match_arm) =
-                parse_variant(callable_type, &enum_name, &output_type, variant)?;
-            variant_structs.push(variant_struct);
+    for (variant, variant_attrs) in data.variants.iter().zip(&context.variants) {
+        let (struct_name, variant_struct, match_arm) =
+            parse_variant(&context, variant, variant_attrs)?;
+        if attrs.no_structs.is_some() {
             match_arms.push(match_arm);
-            Ok::<(), syn::Error>(())
-        })
-        .unwrap();
+            continue;
+        }
+        if let Some(struct_name) = struct_name {
+            // Report clashing names here rather than as a duplicate definition in the output
+            if struct_name == *enum_name || struct_names.contains(&struct_name) {
+                return Err(syn::Error::new(
+                    struct_name.span(),
+                    format!(
+                        "the generated struct name `{}` is already used",
+                        struct_name
+                    ),
+                ));
+            }
+            struct_names.push(struct_name);
+        }
+        variant_structs.push(variant_struct);
+        match_arms.push(match_arm);
+    }
+
+    let scrutinee = callable_type.as_scrutinee();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
 
-    let trait_name = callable_type.as_trait();
-    let fn_type = callable_type.as_fn();
+    let dispatch = match attrs.dispatch {
+        Some(_) => dispatch_impl(&context)?,
+        None => {
+            for variant_attrs in &context.variants {
+                if let (Some(rename), None) = (&variant_attrs.rename, attrs.name) {
+                    return Err(syn::Error::new_spanned(
+                        rename,
+                        "'rename' requires 'dispatch' or 'name' on the enum",
+                    ));
+                }
+                if let Some(span) = variant_attrs.skip {
+                    return Err(syn::Error::new(
+                        span,
+                        "'skip' requires 'dispatch' on the enum",
+                    ));
+                }
+            }
+            TokenStream::new()
+        }
+    };
+    let name = match (attrs.dispatch, attrs.name) {
+        (None, None) => TokenStream::new(),
+        _ => name_impl(&context),
+    };
+    let discriminant = match attrs.discriminant {
+        Some(_) => discriminant_impl(input, data)?,
+        None => TokenStream::new(),
+    };
+    // The impl is always in the crate defining the enum, where the match is exhaustive without a
+    // wildcard arm even if the enum or its variants are `#[non_exhaustive]`
+    let body = quote! { match #scrutinee { #(#match_arms)* } };
+    let (impl_output, impl_body) = match attrs.catch_unwind {
+        Some(_) => catch_unwind(krate, &output_type, body.clone()),
+        None => (output_type.clone(), body.clone()),
+    };
+    let enum_output = match &attrs.enum_output {
+        Some((span, traits)) => enum_output_impl(&context, *span, traits)?,
+        None => TokenStream::new(),
+    };
+    let boxed = match attrs.boxed {
+        Some(span) => boxed_impl(
+            &context,
+            span,
+            &impl_output,
+            attrs.no_structs.is_none().then_some(&struct_names),
+        )?,
+        None => TokenStream::new(),
+    };
+    let call_all = match attrs.call_all {
+        Some(span) => call_all_impl("call_all", &context, span, attrs.dispatch, &impl_output)?,
+        None => TokenStream::new(),
+    };
+    let call_batch = match attrs.call_batch {
+        Some(span) => call_all_impl("call_batch", &context, span, attrs.dispatch, &impl_output)?,
+        None => TokenStream::new(),
+    };
+    let const_eval = match attrs.const_eval {
+        Some(span) => const_eval_impl(
+            &callable_type,
+            span,
+            input,
+            &generics,
+            &attrs.args,
+            &output_type,
+            body,
+        )?,
+        None => TokenStream::new(),
+    };
+
+    let trait_impl = trait_impl(
+        &callable_type,
+        krate,
+        attrs.fn_traits.is_some(),
+        &generics,
+        quote! { #enum_name #ty_generics },
+        &attrs.args,
+        &impl_output,
+        impl_body,
+    );
 
     let expanded = quote! {
+        #enum_output
         #(#variant_structs)*
+        #dispatch
+        #call_all
+        #call_batch
+        #name
+        #discriminant
+        #boxed
+        #const_eval
+        #trait_impl
+    };
+
+    Ok(expanded)
+}
+
+fn callable_struct(
+    callable_type: CallableType,
+    input: &DeriveInput,
+    data: &DataStruct,
+) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+
+    // Fields are bound by name for named structs and as `_0`, `_1`, ... for tuple structs
+    let names = field_bindings(&data.fields);
+    let attrs = parse_container_attributes(&input.attrs, names.iter().cloned())?;
+    let krate = &attrs.krate;
+    let callable_type = callable_type.with_ref(attrs.ref_lifetime.clone())?;
+    check_fn_traits(&callable_type, attrs.fn_traits)?;
+    let output_type = attrs.output.unwrap_or_else(|| quote! { () });
+    // Reported on the `#[argcall]` attribute missing the function, or on the struct without one
+    let func_token = attrs.func.ok_or_else(|| {
+        let message = missing_fn_message("struct", false);
+        match input
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("argcall"))
+        {
+            Some(attr) => syn::Error::new_spanned(attr, message),
+            None => syn::Error::new_spanned(struct_name, message),
+        }
+    })?;
+    check_wrapped_body(
+        "box_output",
+        &callable_type,
+        attrs.box_output,
+        attrs.const_eval,
+    )?;
+    let func_token = match attrs.box_output {
+        Some(_) => box_output(krate, &output_type, func_token),
+        None => func_token,
+    };
+    let func_token = callable_type.wrap(krate, func_token);
+    if let Some(span) = attrs.dispatch {
+        return Err(syn::Error::new(
+            span,
+            "'dispatch' is only supported on enums",
+        ));
+    }
+    if let Some(span) = attrs.no_structs {
+        return Err(syn::Error::new(
+            span,
+            "'no_structs' is only supported on enums",
+        ));
+    }
+    if let Some(span) = attrs.boxed {
+        return Err(syn::Error::new(span, "'boxed' is only supported on enums"));
+    }
+    if let Some(span) = attrs.call_all {
+        return Err(syn::Error::new(
+            span,
+            "'call_all' is only supported on enums",
+        ));
+    }
+    if let Some(span) = attrs.call_batch {
+        return Err(syn::Error::new(
+            span,
+            "'call_batch' is only supported on enums",
+        ));
+    }
+    if !matches!(attrs.struct_vis, Visibility::Inherited) {
+        return Err(syn::Error::new_spanned(
+            &attrs.struct_vis,
+            "'struct_vis' is only supported on enums",
+        ));
+    }
+    if let Some(suffix) = &attrs.struct_suffix {
+        return Err(syn::Error::new_spanned(
+            suffix,
+            "'struct_suffix' is only supported on enums",
+        ));
+    }
+    if let Some(path) = attrs.struct_derive.first() {
+        return Err(syn::Error::new_spanned(
+            path,
+            "'struct_derive' is only supported on enums",
+        ));
+    }
+    if let Some((span, _)) = attrs.enum_output {
+        return Err(syn::Error::new(
+            span,
+            "'enum_output' is only supported on enums",
+        ));
+    }
+    if let Some(span) = attrs.allow_empty {
+        return Err(syn::Error::new(
+            span,
+            "'allow_empty' is only supported on enums",
+        ));
+    }
+
+    let binding_mode = callable_type.as_binding_mode();
+    let pattern = match &data.fields {
+        Fields::Unit => quote! { #struct_name },
+        Fields::Named(_) => quote! { #struct_name { #(#binding_mode #names),* } },
+        Fields::Unnamed(_) => quote! { #struct_name(#(#binding_mode #names),*) },
+    };
+
+    check_wrapped_body(
+        "catch_unwind",
+        &callable_type,
+        attrs.catch_unwind,
+        attrs.const_eval,
+    )?;
+    let bind_args = attrs.args.bind();
+    let generics = impl_generics(&callable_type, &input.generics, &attrs.bound);
+    let scrutinee = callable_type.as_scrutinee();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let body = quote! { #bind_args match #scrutinee { #pattern => #func_token, } };
+    let (impl_output, impl_body) = match attrs.catch_unwind {
+        Some(_) => catch_unwind(krate, &output_type, body.clone()),
+        None => (output_type.clone(), body.clone()),
+    };
+
+    let const_eval = match attrs.const_eval {
+        Some(span) => const_eval_impl(
+            &callable_type,
+            span,
+            input,
+            &generics,
+            &attrs.args,
+            &output_type,
+            body,
+        )?,
+        None => TokenStream::new(),
+    };
+
+    let trait_impl = trait_impl(
+        &callable_type,
+        krate,
+        attrs.fn_traits.is_some(),
+        &generics,
+        quote! { #struct_name #ty_generics },
+        &attrs.args,
+        &impl_output,
+        impl_body,
+    );
+
+    let expanded = quote! {
+        #const_eval
+        #trait_impl
+    };
+
+    Ok(expanded)
+}
+
+/// Generates an inherent `const fn eval` with the same body as `call_fn`, since trait methods
+/// cannot be `const`
+fn const_eval_impl(
+    callable_type: &CallableType,
+    span: Span,
+    input: &DeriveInput,
+    generics: &Generics,
+    args: &CallArgs,
+    output_type: &TokenStream,
+    body: TokenStream,
+) -> Result<TokenStream, syn::Error> {
+    if !matches!(callable_type, CallableType::Callable) {
+        return Err(syn::Error::new(
+            span,
+            "'const' is only supported by #[derive(Callable)] without 'ref'",
+        ));
+    }
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let args_ident = CallArgs::ident();
+    let args_type = args.as_type();
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Evaluates the same function as `call_fn`, usable in const contexts
+            #[allow(unused_variables)]
+            #vis const fn eval(&self, #args_ident: #args_type) -> #output_type {
+                #body
+            }
+        }
+    })
+}
+
+/// Implements the derived trait for `self_type` with `body` as the body of its method. With
+/// `fn_traits` the `Fn` traits are implemented too, and a `Callable` comes from the blanket impl for
+/// `Fn` instead, which would conflict with its own impl.
+#[allow(clippy::too_many_arguments)]
+fn trait_impl(
+    callable_type: &CallableType,
+    krate: &syn::Path,
+    fn_traits: bool,
+    generics: &Generics,
+    self_type: TokenStream,
+    args: &CallArgs,
+    output_type: &TokenStream,
+    body: TokenStream,
+) -> TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let fn_type = callable_type.as_fn(args);
+    let trait_name = callable_type.as_impl_trait(krate, &args.as_type());
+    let associated_types = callable_type.as_associated_types(krate, output_type);
+    let return_type = callable_type.as_return_type(output_type);
+    let trait_impl = quote! {
+        impl #impl_generics #trait_name for #self_type #where_clause {
+            #associated_types
+            #[inline]
+            #[allow(unused_variables, unused_mut)]
+            fn #fn_type -> #return_type {
+                #body
+            }
+        }
+    };
+
+    if fn_traits {
+        let args_ident = CallArgs::ident();
+        let args_type = args.as_type();
+        let fn_once = |body: TokenStream| {
+            quote! {
+                impl #impl_generics ::core::ops::FnOnce<#args_type> for #self_type #where_clause {
+                    type Output = #output_type;
+                    #[inline]
+                    extern "rust-call" fn call_once(self, #args_ident: #args_type) -> #output_type {
+                        #body
+                    }
+                }
+            }
+        };
+        let fn_mut = |body: TokenStream| {
+            quote! {
+                impl #impl_generics ::core::ops::FnMut<#args_type> for #self_type #where_clause {
+                    #[inline]
+                    extern "rust-call" fn call_mut(&mut self, #args_ident: #args_type) -> #output_type {
+                        #body
+                    }
+                }
+            }
+        };
+        match callable_type {
+            CallableType::Callable => {
+                let fn_once = fn_once(quote! { ::core::ops::Fn::call(&self, #args_ident) });
+                let fn_mut = fn_mut(quote! { ::core::ops::Fn::call(self, #args_ident) });
+                return quote! {
+                    #fn_once
+                    #fn_mut
+                    impl #impl_generics ::core::ops::Fn<#args_type> for #self_type #where_clause {
+                        #[inline]
+                        #[allow(unused_variables)]
+                        extern "rust-call" fn call(&self, #args_ident: #args_type) -> #output_type {
+                            #body
+                        }
+                    }
+                };
+            }
+            CallableType::CallableMut => {
+                let fn_once = fn_once(quote! {
+                    let mut this = self;
+                    <Self as #trait_name>::call_fn_mut(&mut this, #args_ident)
+                });
+                let fn_mut =
+                    fn_mut(quote! { <Self as #trait_name>::call_fn_mut(self, #args_ident) });
+                return quote! {
+                    #trait_impl
+                    #fn_once
+                    #fn_mut
+                };
+            }
+            CallableType::CallableOnce => {
+                let fn_once =
+                    fn_once(quote! { <Self as #trait_name>::call_fn_once(self, #args_ident) });
+                return quote! {
+                    #trait_impl
+                    #fn_once
+                };
+            }
+            _ => {}
+        }
+    }
+
+    trait_impl
+}
+
+/// The integer types an enum can be `#[repr]`ed as, which are the types of its discriminants
+const REPR_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Generates `discriminant`, returning the discriminant of each variant as the `#[repr]` type of the
+/// enum, or `isize` without one; the variants without a discriminant count up from the previous
+/// variant as in Rust
+fn discriminant_impl(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream, syn::Error> {
+    let mut repr = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        repr = metas
+            .iter()
+            .filter_map(|meta| meta.path().get_ident())
+            .find(|ident| REPR_TYPES.iter().any(|ty| *ident == ty))
+            .cloned()
+            .or(repr);
+    }
+    let repr = repr.map_or_else(|| quote! { isize }, ToTokens::into_token_stream);
+
+    let vis = &input.vis;
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut previous = None;
+    let arms = data.variants.iter().map(|variant| {
+        let value = match (&variant.discriminant, &previous) {
+            (Some((_, expr)), _) => quote! { (#expr) as #repr },
+            (None, Some(previous)) => quote! { #previous + 1 },
+            (None, None) => quote! { 0 },
+        };
+        previous = Some(value.clone());
+        let variant_name = &variant.ident;
+        quote! { #enum_name::#variant_name { .. } => #value, }
+    });
+    let arms = arms.collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// Returns the discriminant of the variant
+            #vis const fn discriminant(&self) -> #repr {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// The traits `enum_output` can implement for the generated enum by delegating to the variants
+const ENUM_OUTPUT_TRAITS: &[&str] = &["Iterator", "DoubleEndedIterator", "ExactSizeIterator"];
+
+/// Generates the output enum of `enum_output`, holding the output of each variant in a variant of
+/// the same name, and the listed traits for it
+fn enum_output_impl(
+    context: &EnumContext,
+    span: Span,
+    traits: &[Ident],
+) -> Result<TokenStream, syn::Error> {
+    let EnumContext {
+        callable_type,
+        krate,
+        input,
+        data,
+        output_type,
+        args,
+        ..
+    } = *context;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "'enum_output' is not supported on generic enums",
+        ));
+    }
+    if data.variants.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "'enum_output' requires at least one variant",
+        ));
+    }
+    if let Some(unknown) = traits
+        .iter()
+        .find(|name| !ENUM_OUTPUT_TRAITS.iter().any(|known| *name == known))
+    {
+        return Err(syn::Error::new(
+            unknown.span(),
+            format!(
+                "'enum_output' cannot implement `{}`, expected one of {}",
+                unknown,
+                ENUM_OUTPUT_TRAITS.join(", ")
+            ),
+        ));
+    }
 
-        impl #trait_name for #enum_name {
-            type Output = #output_type;
-            fn #fn_type -> #output_type {
-                match self {
-                    #(#match_arms)*
+    // Delegating variants hold the output of their inner callable, other variants the type given
+    // with their `output`
+    let trait_name = callable_type.as_impl_trait(krate, &args.as_type());
+    let mut variant_names = Vec::new();
+    let mut variant_types = Vec::new();
+    for (variant, attrs) in data.variants.iter().zip(&context.variants) {
+        let variant_type = match (&variant.fields, &attrs.output) {
+            (_, Some(output)) => output.clone(),
+            (Fields::Unnamed(fields), None)
+                if attrs.func.is_none() && attrs.default.is_none() && fields.unnamed.len() == 1 =>
+            {
+                let field_type = &fields.unnamed[0].ty;
+                quote! { <#field_type as #trait_name>::Output }
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "'enum_output' requires an 'output' on each variant that does not delegate to an inner callable",
+                ));
+            }
+        };
+        variant_names.push(&variant.ident);
+        variant_types.push(variant_type);
+    }
+
+    let vis = &input.vis;
+    let doc = format!(
+        "The output of [`{}`], holding the output of the called variant",
+        input.ident
+    );
+    let first_type = &variant_types[0];
+    let impls = traits.iter().map(|name| match name.to_string().as_str() {
+        "Iterator" => quote! {
+            impl ::core::iter::Iterator for #output_type {
+                type Item = <#first_type as ::core::iter::Iterator>::Item;
+
+                #[inline]
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    match self {
+                        #(Self::#variant_names(inner) => inner.next(),)*
+                    }
+                }
+
+                #[inline]
+                fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                    match self {
+                        #(Self::#variant_names(inner) => inner.size_hint(),)*
+                    }
+                }
+            }
+        },
+        "DoubleEndedIterator" => quote! {
+            impl ::core::iter::DoubleEndedIterator for #output_type {
+                #[inline]
+                fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+                    match self {
+                        #(Self::#variant_names(inner) => inner.next_back(),)*
+                    }
+                }
+            }
+        },
+        _ => quote! {
+            impl ::core::iter::ExactSizeIterator for #output_type {
+                #[inline]
+                fn len(&self) -> usize {
+                    match self {
+                        #(Self::#variant_names(inner) => inner.len(),)*
+                    }
                 }
             }
+        },
+    });
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis enum #output_type {
+            #(
+                #[allow(missing_docs)]
+                #variant_names(#variant_types),
+            )*
+        }
+
+        #(#impls)*
+    })
+}
+
+/// Generates `call_all`, or `call_batch` when given as `key`, calling every variant listed by `all` in
+/// declaration order
+fn call_all_impl(
+    key: &str,
+    context: &EnumContext,
+    span: Span,
+    dispatch: Option<Span>,
+    output_type: &TokenStream,
+) -> Result<TokenStream, syn::Error> {
+    let EnumContext {
+        callable_type,
+        krate,
+        input,
+        generics,
+        args,
+        ..
+    } = *context;
+    if dispatch.is_none() {
+        return Err(syn::Error::new(
+            span,
+            format!("'{key}' requires 'dispatch'"),
+        ));
+    }
+    if !matches!(callable_type, CallableType::Callable) {
+        return Err(syn::Error::new(
+            span,
+            format!("'{key}' is only supported by #[derive(Callable)] without 'ref'"),
+        ));
+    }
+
+    let vis = &input.vis;
+    let enum_name = &input.ident;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let args_ident = CallArgs::ident();
+    let args_type = args.as_type();
+    // The arguments are cloned for each variant, and can be left out when there are none
+    let (params, call_args) = match args.types.is_empty() {
+        true => (TokenStream::new(), quote! { () }),
+        false => (
+            quote! { #args_ident: #args_type },
+            quote! { ::core::clone::Clone::clone(&#args_ident) },
+        ),
+    };
+
+    // `call_batch` keeps up to 8 outputs inline instead of allocating
+    let (doc, collection) = match key {
+        "call_batch" => (
+            "Calls every variant in declaration order and collects the outputs, inline for up to 8 variants",
+            quote! { #krate::__private::SmallVec<[#output_type; 8]> },
+        ),
+        _ => (
+            "Calls every variant in declaration order and collects the outputs",
+            quote! { #krate::__private::Vec<#output_type> },
+        ),
+    };
+    let method = Ident::new(key, span);
+
+    Ok(quote! {
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            #[doc = #doc]
+            #vis fn #method(#params) -> #collection
+            where
+                #args_type: ::core::clone::Clone,
+            {
+                Self::all()
+                    .iter()
+                    .map(|variant| #krate::Callable::call_fn(variant, #call_args))
+                    .collect()
+            }
         }
+    })
+}
+
+/// Rejects `catch_unwind` and `box_output`, given as `key`, where the body cannot be wrapped
+fn check_wrapped_body(
+    key: &str,
+    callable_type: &CallableType,
+    wrapper: Option<Span>,
+    const_eval: Option<Span>,
+) -> Result<(), syn::Error> {
+    let Some(span) = wrapper else {
+        return Ok(());
     };
+    if matches!(callable_type, CallableType::AsyncCallable) {
+        return Err(syn::Error::new(
+            span,
+            format!("'{key}' is not supported on async callables"),
+        ));
+    }
+    if const_eval.is_some() {
+        return Err(syn::Error::new(
+            span,
+            format!("'{key}' cannot be combined with 'const'"),
+        ));
+    }
+    Ok(())
+}
 
-    proc_macro::TokenStream::from(expanded)
+/// Boxes the value of a bound function, coercing it to the boxed `output_type`
+fn box_output(krate: &syn::Path, output_type: &TokenStream, func: TokenStream) -> TokenStream {
+    quote! {{
+        let output: #output_type = #krate::__private::Box::new(#func);
+        output
+    }}
 }
 
-fn parse_variant(
-    callable_type: CallableType,
-    enum_name: &Ident,
+/// Wraps `body` in `catch_unwind`, returning the output type of the wrapped body along with it
+fn catch_unwind(
+    krate: &syn::Path,
+    output_type: &TokenStream,
+    body: TokenStream,
+) -> (TokenStream, TokenStream) {
+    let output = quote! {
+        ::core::result::Result<#output_type, #krate::__private::Box<dyn ::core::any::Any + ::core::marker::Send>>
+    };
+    let body = quote! {
+        #krate::__private::catch_unwind(#krate::__private::AssertUnwindSafe(|| { #body }))
+    };
+    (output, body)
+}
+
+/// Generates `From<&Enum>` for a boxed `dyn Callable`, returning the struct generated for the
+/// variant
+fn boxed_impl(
+    context: &EnumContext,
+    span: Span,
     output_type: &TokenStream,
+    struct_names: Option<&Vec<Ident>>,
+) -> Result<TokenStream, syn::Error> {
+    let EnumContext {
+        callable_type,
+        krate,
+        input,
+        data,
+        generics,
+        args,
+        ..
+    } = *context;
+    if !matches!(callable_type, CallableType::Callable) {
+        return Err(syn::Error::new(
+            span,
+            "'boxed' is only supported by #[derive(Callable)] without 'ref'",
+        ));
+    }
+    let Some(struct_names) = struct_names else {
+        return Err(syn::Error::new(
+            span,
+            "'boxed' cannot be combined with 'no_structs'",
+        ));
+    };
+    for (variant, attrs) in data.variants.iter().zip(&context.variants) {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "'boxed' requires all variants to be unit variants",
+            ));
+        }
+        if let Some(output) = &attrs.output {
+            return Err(syn::Error::new_spanned(
+                output,
+                "'boxed' does not support variant outputs",
+            ));
+        }
+    }
+
+    let enum_name = &input.ident;
+    let variants = data.variants.iter().map(|variant| &variant.ident);
+    let args_type = args.as_type();
+    // Unit-only enums cannot use type or lifetime parameters, so the structs hold no data
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<&#enum_name #ty_generics>
+            for #krate::__private::Box<dyn #krate::Callable<#args_type, Output = #output_type>>
+            #where_clause
+        {
+            fn from(value: &#enum_name #ty_generics) -> Self {
+                match *value {
+                    #(#enum_name::#variants => #krate::__private::Box::new(#struct_names),)*
+                }
+            }
+        }
+    })
+}
+
+/// Infers the output of an enum from its first tuple variant that delegates to an inner callable
+fn infer_output(
+    callable_type: &CallableType,
+    krate: &syn::Path,
+    args: &CallArgs,
+    data: &DataEnum,
+    variants: &[VariantAttributes],
+) -> Option<TokenStream> {
+    for (variant, attrs) in data.variants.iter().zip(variants) {
+        let Fields::Unnamed(fields) = &variant.fields else {
+            continue;
+        };
+        if attrs.func.is_some() || attrs.default.is_some() || fields.unnamed.len() != 1 {
+            continue;
+        }
+        if let Some(output) = &attrs.output {
+            return Some(output.clone());
+        }
+        let trait_name = callable_type.as_impl_trait(krate, &args.as_type());
+        let field_type = &fields.unnamed[0].ty;
+        return Some(quote! { <#field_type as #trait_name>::Output });
+    }
+    None
+}
+
+/// Generates `from_name` and `all` for an enum whose variants are all unit variants
+fn dispatch_impl(context: &EnumContext) -> Result<TokenStream, syn::Error> {
+    let EnumContext {
+        input,
+        data,
+        generics,
+        ..
+    } = *context;
+    let vis = &input.vis;
+    let enum_name = &input.ident;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    // `TryFrom<&str>` borrows the name for a lifetime of its own, which cannot collide with the
+    // lifetimes of the enum
+    let name_lifetime = Lifetime::new("'__argcall_name", Span::call_site());
+    let mut try_from_generics = generics.clone();
+    try_from_generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeParam::new(name_lifetime.clone())),
+    );
+    let (try_from_impl_generics, _, _) = try_from_generics.split_for_impl();
+    let mut variants = Vec::new();
+    // Variants are looked up by their `rename`, or by their identifier without the `r#` prefix
+    let mut names: Vec<LitStr> = Vec::new();
+    for (variant, attrs) in data.variants.iter().zip(&context.variants) {
+        // Skipped variants can only be constructed directly
+        if let (Some(_), Some(rename)) = (attrs.skip, &attrs.rename) {
+            return Err(syn::Error::new_spanned(
+                rename,
+                "'rename' cannot be combined with 'skip'",
+            ));
+        }
+        let name = variant_name(variant, attrs.rename.as_ref());
+        if attrs.skip.is_some() {
+            continue;
+        }
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "'dispatch' requires all variants to be unit variants, other variants can be skipped with 'skip'",
+            ));
+        }
+        if names.iter().any(|other| other.value() == name.value()) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!(
+                    "the name \"{}\" is already used by another variant",
+                    name.value()
+                ),
+            ));
+        }
+        names.push(name);
+        variants.push(&variant.ident);
+    }
+    let error_name = format_ident!("{}NameError", enum_name);
+    let error_doc = format!(
+        "The error returned when parsing a name that is not a variant of [`{}`]",
+        enum_name
+    );
+
+    Ok(quote! {
+        #[doc = #error_doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #vis struct #error_name<'a> {
+            name: &'a str,
+        }
+
+        impl<'a> #error_name<'a> {
+            /// Returns the name that was not recognized
+            #vis fn name(&self) -> &'a str {
+                self.name
+            }
+
+            /// Returns the names of all variants
+            #vis fn expected() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+        }
+
+        impl ::core::fmt::Display for #error_name<'_> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, "unknown variant `{}`, expected one of ", self.name)?;
+                for (i, name) in Self::expected().iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    ::core::write!(f, "`{}`", name)?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl ::core::error::Error for #error_name<'_> {}
+
+        impl #try_from_impl_generics ::core::convert::TryFrom<&#name_lifetime str>
+            for #enum_name #ty_generics #where_clause
+        {
+            type Error = #error_name<#name_lifetime>;
+
+            fn try_from(name: &#name_lifetime str) -> ::core::result::Result<Self, Self::Error> {
+                Self::from_name(name).ok_or(#error_name { name })
+            }
+        }
+
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// Returns the variant with the given name, if any
+            #vis fn from_name(name: &str) -> ::core::option::Option<Self> {
+                match name {
+                    #(#names => ::core::option::Option::Some(Self::#variants),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            /// Returns all variants in declaration order
+            #vis fn all() -> &'static [Self] {
+                const { &[#(Self::#variants),*] }
+            }
+        }
+    })
+}
+
+/// The name of a variant: its `rename`, or its identifier without the `r#` prefix
+fn variant_name(variant: &Variant, rename: Option<&LitStr>) -> LitStr {
+    rename
+        .cloned()
+        .unwrap_or_else(|| LitStr::new(&variant.ident.unraw().to_string(), variant.ident.span()))
+}
+
+/// Generates `name` for `dispatch` or `name`, returning the name of each variant as matched by
+/// `from_name`
+fn name_impl(context: &EnumContext) -> TokenStream {
+    let EnumContext { input, data, .. } = *context;
+    let vis = &input.vis;
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let arms = data
+        .variants
+        .iter()
+        .zip(&context.variants)
+        .map(|(variant, attrs)| {
+            let name = variant_name(variant, attrs.rename.as_ref());
+            let variant_name = &variant.ident;
+            quote! { #enum_name::#variant_name { .. } => #name, }
+        });
+
+    quote! {
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// Returns the name of the variant, such as for logging
+            #vis const fn name(&self) -> &'static str {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+    }
+}
+
+/// The generics of the generated impls: the predicates of a `bound` attribute are added to the
+/// `where` clause, and the lifetime of `CallableRef` is declared unless the type already has it
+fn impl_generics(
+    callable_type: &CallableType,
+    generics: &Generics,
+    bound: &[WherePredicate],
+) -> Generics {
+    let mut generics = generics.clone();
+    if let CallableType::CallableRef(lifetime) = callable_type {
+        if generics
+            .lifetimes()
+            .all(|param| param.lifetime != *lifetime)
+        {
+            generics.params.insert(
+                0,
+                GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())),
+            );
+        }
+        // The fields are borrowed for the lifetime, so they have to outlive it
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! { Self: #lifetime });
+    }
+    if !bound.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(bound.iter().cloned());
+    }
+    generics
+}
+
+/// Moves every token to the location of `span` while keeping its hygiene, so errors about the
+/// tokens are reported there
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(group.span().located_at(span));
+                return TokenTree::Group(respanned);
+            }
+            token.set_span(token.span().located_at(span));
+            token
+        })
+        .collect()
+}
+
+/// Replaces `self` in an expression with `value` and `Self` with `self_type`, leaving paths
+/// starting with `self::` as they are
+fn replace_self(tokens: TokenStream, value: &TokenStream, self_type: &TokenStream) -> TokenStream {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut replaced = TokenStream::new();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    replace_self(group.stream(), value, self_type),
+                );
+                new_group.set_span(group.span());
+                replaced.extend([TokenTree::Group(new_group)]);
+            }
+            TokenTree::Ident(ident)
+                if ident == "self"
+                    && !matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == ':') =>
+            {
+                replaced.extend(value.clone());
+            }
+            TokenTree::Ident(ident) if ident == "Self" => replaced.extend(self_type.clone()),
+            token => replaced.extend([token]),
+        }
+    }
+    replaced
+}
+
+fn field_bindings(fields: &Fields) -> Vec<Ident> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => Ident::new(&format!("_{}", i), field.span()),
+        })
+        .collect()
+}
+
+/// Builds the `PhantomData` field a generated struct needs to carry the type and lifetime
+/// parameters of `generics`, or `None` when there are none to carry.
+fn phantom_data(generics: &Generics) -> Option<TokenStream> {
+    let params = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                Some(quote! { &#lifetime () })
+            }
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                Some(quote! { #ident })
+            }
+            GenericParam::Const(_) => None,
+        })
+        .collect::<Vec<_>>();
+    if params.is_empty() {
+        None
+    } else {
+        Some(quote! { ::core::marker::PhantomData<fn() -> (#(#params,)*)> })
+    }
+}
+
+/// The parts of an enum derive shared by all of its variants
+struct EnumContext<'a> {
+    callable_type: &'a CallableType,
+    /// Whether an `async_fn` made a `Callable` enum an `AsyncCallable`, so the variants without
+    /// one are evaluated as for `Callable` and wrapped in a ready future
+    ready_sync: bool,
+    /// Whether panics are caught and returned as errors
+    catch_unwind: bool,
+    /// Whether the values of the bound functions are boxed into the output
+    box_output: bool,
+    /// Whether the output is the generated enum with a variant for each variant
+    enum_output: bool,
+    /// The function bound on the enum, called by the unit variants without one
+    fallback: Option<&'a TokenStream>,
+    krate: &'a syn::Path,
+    input: &'a DeriveInput,
+    data: &'a DataEnum,
+    /// The attributes of each variant, in the order of `data`
+    variants: Vec<VariantAttributes>,
+    /// The generics of the enum with the `bound` attribute applied, used for the generated impls
+    generics: &'a Generics,
+    output_type: &'a TokenStream,
+    args: &'a CallArgs,
+    struct_vis: &'a Visibility,
+    struct_suffix: &'a str,
+    /// Whether the `Fn` traits are implemented for the generated structs
+    fn_traits: bool,
+    /// The traits derived for the generated structs
+    struct_derive: &'a [syn::Path],
+}
+
+fn parse_variant(
+    context: &EnumContext,
     variant: &Variant,
-) -> Result<(TokenStream, TokenStream), syn::Error> {
+    attrs: &VariantAttributes,
+) -> Result<(Option<Ident>, TokenStream, TokenStream), syn::Error> {
+    let EnumContext {
+        callable_type,
+        ready_sync,
+        catch_unwind: catches_unwind,
+        box_output: boxes_output,
+        enum_output,
+        fallback,
+        krate,
+        input,
+        generics,
+        output_type,
+        args,
+        struct_vis,
+        struct_suffix,
+        fn_traits,
+        struct_derive,
+        ..
+    } = *context;
+    let enum_name = &input.ident;
     let variant_name = variant.ident.clone();
 
-    let func_token = variant
-        .attrs
-        .iter()
-        .filter(|attr| attr.path().is_ident("argcall"));
+    let names = field_bindings(&variant.fields);
+    let mut func = attrs.func.clone();
+
+    if let (Some(span), false) = (
+        attrs.async_fn,
+        matches!(callable_type, CallableType::AsyncCallable),
+    ) {
+        return Err(syn::Error::new(
+            span,
+            "'async_fn' is only supported by #[derive(Callable)] and #[derive(AsyncCallable)]",
+        ));
+    }
+    let sync = ready_sync && attrs.async_fn.is_none();
+    if let (Fields::Unit, None, None, Some(fallback)) =
+        (&variant.fields, &func, attrs.default, fallback)
+    {
+        func = Some(fallback.clone());
+    }
+    if boxes_output {
+        let boxed_type = attrs.output.as_ref().unwrap_or(output_type);
+        func = func.map(|func| box_output(krate, boxed_type, func));
+    }
+    // `default` returns the default value of the variant output
+    if let Some(span) = attrs.default {
+        let default_type = attrs.output.as_ref().unwrap_or(output_type);
+        let value = quote_spanned! {span=> <#default_type as ::core::default::Default>::default() };
+        func = Some(match (callable_type, sync) {
+            (CallableType::AsyncCallable, false) => quote! { ::core::future::ready(#value) },
+            _ => value,
+        });
+    }
+    let ready = |body: TokenStream| match sync {
+        true => quote! { ::core::future::ready(#body) },
+        false => body,
+    };
+
+    // A variant-level output is converted into the enum output with `Into`, or wrapped in the
+    // variant of the generated output enum
+    let convert = |body: TokenStream| match (&attrs.output, enum_output) {
+        (_, true) => quote! { #output_type::#variant_name(#body) },
+        (Some(_), false) => quote! { ::core::convert::Into::<#output_type>::into(#body) },
+        (None, false) => body,
+    };
+    if let (CallableType::AsyncCallable, false, Some(output)) = (callable_type, sync, &attrs.output)
+    {
+        return Err(syn::Error::new_spanned(
+            output,
+            "'output' is not supported on async variants",
+        ));
+    }
+
+    let args_type = args.as_type();
+    let bind_args = args.bind();
+
+    let binding_mode = callable_type.as_binding_mode();
+    let missing_fn = || syn::Error::new_spanned(variant, missing_fn_message("variant", true));
+
+    if let (Some(delegate), false) = (
+        &attrs.delegate,
+        matches!(variant.fields, Fields::Unnamed(_)) && func.is_none(),
+    ) {
+        return Err(syn::Error::new_spanned(
+            delegate,
+            "'delegate' is only supported on tuple variants delegating to an inner callable",
+        ));
+    }
 
-    let trait_name = callable_type.as_trait();
-    let fn_type = callable_type.as_fn();
+    if let (false, Some(struct_name)) = (matches!(variant.fields, Fields::Unit), &attrs.struct_name)
+    {
+        return Err(syn::Error::new(
+            struct_name.span(),
+            "'struct_name' is only supported on unit variants",
+        ));
+    }
 
     match &variant.fields {
         Fields::Unit => {
-            let func_token = func_token
-                .map(|attr| parse_fn_attribute(attr, std::iter::empty()))
-                .next()
-                .unwrap_or_else(|| {
-                    Err(syn::Error::new_spanned(
-                        variant,
-                        "expected an 'argcall' attribute",
-                    ))
-                })?;
+            let func_token = func.ok_or_else(missing_fn)?;
 
-            let struct_name = Ident::new(
-                &format!("{}{}Callable", enum_name, variant_name),
-                variant_name.span(),
-            );
+            let struct_name = attrs.struct_name.clone().unwrap_or_else(|| {
+                Ident::new(
+                    &format!("{}{}{}", enum_name, variant_name, struct_suffix),
+                    variant_name.span(),
+                )
+            });
 
-            // Generate the struct for the variant
-            let variant_struct = quote! {
-                #[derive(Clone, Debug)]
-                pub struct #struct_name;
+            // The generated struct produces the variant-level output when one is given
+            let struct_output = attrs.output.as_ref().unwrap_or(output_type);
 
-                impl #trait_name for #struct_name {
-                    type Output = #output_type;
-                    fn #fn_type -> #output_type {
-                        #func_token
+            // Generate the struct for the variant, carrying the enum's generics if it has any
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+            // `self` is the enum value and `Self` the enum type in the bound function, also when
+            // called through the struct
+            let variant_value = callable_type
+                .as_receiver_value(quote! { <#enum_name #ty_generics>::#variant_name });
+            let turbofish = ty_generics.as_turbofish();
+            let struct_body = callable_type.wrap(
+                krate,
+                ready(replace_self(
+                    func_token.clone(),
+                    &variant_value,
+                    &quote! { #enum_name #turbofish },
+                )),
+            );
+            let definition = match phantom_data(&input.generics) {
+                Some(phantom) => quote! {
+                    #struct_vis struct #struct_name #impl_generics (#struct_vis #phantom) #where_clause;
+                },
+                None => quote! {
+                    #struct_vis struct #struct_name #impl_generics #where_clause;
+                },
+            };
+            let struct_body = quote! { #bind_args #struct_body };
+            let (struct_output, struct_body) = match catches_unwind {
+                true => catch_unwind(krate, struct_output, struct_body),
+                false => (struct_output.clone(), struct_body),
+            };
+            let struct_impl = trait_impl(
+                callable_type,
+                krate,
+                fn_traits,
+                generics,
+                quote! { #struct_name #ty_generics },
+                args,
+                &struct_output,
+                struct_body,
+            );
+            let derive =
+                (!struct_derive.is_empty()).then(|| quote! { #[derive(#(#struct_derive),*)] });
+            // The struct converts back into the variant it was generated for
+            let from_impl = quote! {
+                impl #impl_generics ::core::convert::From<#struct_name #ty_generics>
+                    for #enum_name #ty_generics #where_clause
+                {
+                    #[inline]
+                    fn from(_: #struct_name #ty_generics) -> Self {
+                        #enum_name::#variant_name
                     }
                 }
             };
+            let variant_struct = quote! {
+                #derive
+                #[allow(dead_code, non_camel_case_types)]
+                #definition
+                #struct_impl
+                #from_impl
+            };
 
+            let body = callable_type.wrap(krate, ready(convert(func_token)));
             let match_arm = quote! {
-                #enum_name::#variant_name => #func_token,
+                #enum_name::#variant_name => {
+                    #bind_args
+                    #body
+                }
             };
-            Ok((variant_struct, match_arm))
+            Ok((Some(struct_name), variant_struct, match_arm))
         }
-        Fields::Unnamed(_) => {
-            // like this:
-            // #enum_name::#variant_name(value) => argcall::Callable::call_fn(value, ()),
-            let match_arm = quote! {
-                #enum_name::#variant_name(value) =>
-            };
+        Fields::Unnamed(_) if func.is_some() => {
+            let func_token = func.ok_or_else(missing_fn)?;
+            let body = callable_type.wrap(krate, ready(convert(func_token)));
 
-            let match_arm = match callable_type {
-                CallableType::Callable => {
-                    quote! { #match_arm argcall::Callable::call_fn(value, ()), }
+            let match_arm = quote! {
+                #enum_name::#variant_name(#(#binding_mode #names),*) => {
+                    #bind_args
+                    #body
                 }
-                CallableType::CallableMut => {
-                    quote! { #match_arm argcall::CallableMut::call_fn_mut(value, ()), }
+            };
+            Ok((None, TokenStream::new(), match_arm))
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() != 1 => Err(syn::Error::new_spanned(
+            fields,
+            "a tuple variant without 'fn' must have exactly one field to delegate to",
+        )),
+        Fields::Unnamed(fields) => {
+            // like this:
+            // #enum_name::#variant_name(value) => <Inner as argcall::Callable<()>>::call_fn(value, args),
+            // Without an `async_fn`, the inner value of a promoted enum is a `Callable`. With
+            // `delegate = "ref"`, the inner value of a `CallableMut` or `CallableOnce` is borrowed and
+            // called as a `Callable` instead
+            let by_ref = attrs
+                .delegate
+                .as_ref()
+                .is_some_and(|delegate| delegate.value() == "ref");
+            let (method, value) = match callable_type {
+                _ if sync => (CallableType::Callable.as_method(), quote! { value }),
+                CallableType::CallableMut if by_ref => {
+                    (quote! { call_fn_by_ref }, quote! { &*value })
                 }
-                CallableType::CallableOnce => {
-                    quote! { #match_arm argcall::CallableOnce::call_fn_once(value, ()), }
+                CallableType::CallableOnce if by_ref => {
+                    (quote! { call_fn_by_ref }, quote! { &value })
                 }
+                _ => (callable_type.as_method(), quote! { value }),
             };
-            Ok((TokenStream::new(), match_arm))
+            // Spanned on the field so an inner type that is not callable is reported there, by the
+            // message of the delegate trait checked in `__private::delegate`
+            let field_type = &fields.unnamed[0].ty;
+            let args_ident = CallArgs::ident();
+            let call = respan(
+                quote! { #krate::__private::delegate::#method::<#field_type, #args_type, _>(#value, #args_ident) },
+                field_type.span(),
+            );
+            let call = callable_type.wrap(krate, ready(convert(call)));
+            let match_arm = quote! {
+                #enum_name::#variant_name(#binding_mode value) => #call,
+            };
+            Ok((None, TokenStream::new(), match_arm))
         }
-        Fields::Named(fields) => {
-            let names = fields
-                .named
-                .iter()
-                .map(|field| field.ident.clone().unwrap());
-            let func_token = func_token
-                .map(|attr| parse_fn_attribute(attr, names.clone()))
-                .next()
-                .unwrap_or_else(|| {
-                    Err(syn::Error::new_spanned(
-                        variant,
-                        "expected an 'argcall' attribute",
-                    ))
-                })?;
+        Fields::Named(_) => {
+            let func_token = func.ok_or_else(missing_fn)?;
+            let body = callable_type.wrap(krate, ready(convert(func_token)));
 
             let match_arm = quote! {
-                #enum_name::#variant_name { #(#names),* } => #func_token,
+                #enum_name::#variant_name { #(#binding_mode #names),* } => {
+                    #bind_args
+                    #body
+                }
             };
-            Ok((TokenStream::new(), match_arm))
+            Ok((None, TokenStream::new(), match_arm))
         }
     }
 }
 
-fn parse_output_attribute(attr: &Attribute) -> Result<proc_macro2::TokenStream, syn::Error> {
+struct ContainerAttributes {
+    output: Option<TokenStream>,
+    func: Option<TokenStream>,
+    args: CallArgs,
+    dispatch: Option<Span>,
+    /// Set when `discriminant` is generated
+    discriminant: Option<Span>,
+    /// Set when `name` is generated without `dispatch`
+    name: Option<Span>,
+    boxed: Option<Span>,
+    call_all: Option<Span>,
+    call_batch: Option<Span>,
+    catch_unwind: Option<Span>,
+    box_output: Option<Span>,
+    /// The traits implemented by the generated output enum, given with `enum_output(...)`
+    enum_output: Option<(Span, Vec<Ident>)>,
+    no_structs: Option<Span>,
+    /// Set when an enum without variants is derived on purpose
+    allow_empty: Option<Span>,
+    const_eval: Option<Span>,
+    /// Set when the nightly `Fn` traits are implemented as well
+    fn_traits: Option<Span>,
+    struct_vis: Visibility,
+    struct_suffix: Option<LitStr>,
+    struct_derive: Vec<syn::Path>,
+    bound: Vec<WherePredicate>,
+    ref_lifetime: Option<Lifetime>,
+    /// The path of the argcall crate in the generated code, given with `crate = <path>`
+    krate: syn::Path,
+}
+
+fn parse_container_attributes(
+    attrs: &[Attribute],
+    args: impl Iterator<Item = Ident>,
+) -> Result<ContainerAttributes, syn::Error> {
     let mut output = None;
+    let mut func = None;
+    let mut arg_order = None;
+    let mut call_args = CallArgs::default();
+    let mut ctx = None;
+    let mut try_output = None;
+    let mut error = None;
+    let mut dispatch = None;
+    let mut discriminant = None;
+    let mut name = None;
+    let mut boxed = None;
+    let mut call_all = None;
+    let mut call_batch = None;
+    let mut catch_unwind = None;
+    let mut box_output = None;
+    let mut enum_output = None;
+    let mut no_structs = None;
+    let mut allow_empty = None;
+    let mut const_eval = None;
+    let mut fn_traits = None;
+    let mut struct_vis = Visibility::Inherited;
+    let mut struct_suffix = None;
+    let mut struct_derive = Vec::new();
+    let mut bound = Vec::new();
+    let mut ref_lifetime = None;
+    let mut krate = syn::parse_quote! { argcall };
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("argcall")) {
+        attr.parse_nested_meta(|meta| {
+            let ident = meta.path.require_ident()?;
+            if ident == "output" {
+                output = Some(parse_output(&meta)?.into_token_stream());
+                return Ok(());
+            }
+            if ident == "args" {
+                call_args = CallArgs::parse(meta.value()?)?;
+                return Ok(());
+            }
+            if ident == "ctx" {
+                let value: Type = meta.value()?.parse()?;
+                ctx = Some((ident.clone(), value));
+                return Ok(());
+            }
+            if ident == "struct_vis" {
+                let value: LitStr = meta.value()?.parse()?;
+                struct_vis = value.parse().map_err(|_| {
+                    syn::Error::new_spanned(&value, "expected a visibility like \"pub(crate)\"")
+                })?;
+                return Ok(());
+            }
+            if ident == "ref" {
+                // `ref` borrows for `'a` unless another lifetime is given with `ref = 'x`
+                ref_lifetime = Some(if meta.input.peek(Token![=]) {
+                    meta.value()?.parse()?
+                } else {
+                    Lifetime::new("'a", ident.span())
+                });
+                return Ok(());
+            }
+            if ident == "crate" {
+                krate = meta.value()?.call(syn::Path::parse_mod_style)?;
+                return Ok(());
+            }
+            if ident == "bound" {
+                let value: LitStr = meta.value()?.parse()?;
+                let predicates =
+                    value.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+                bound.extend(predicates);
+                return Ok(());
+            }
+            if ident == "struct_suffix" {
+                let value: LitStr = meta.value()?.parse()?;
+                value.parse::<Ident>()?;
+                struct_suffix = Some(value);
+                return Ok(());
+            }
+            if ident == "enum_output" {
+                let content;
+                parenthesized!(content in meta.input);
+                let traits = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+                enum_output = Some((ident.span(), traits.into_iter().collect()));
+                return Ok(());
+            }
+            if ident == "struct_derive" {
+                let content;
+                parenthesized!(content in meta.input);
+                struct_derive.extend(Punctuated::<syn::Path, Token![,]>::parse_terminated(
+                    &content,
+                )?);
+                return Ok(());
+            }
+            if ident == "const" {
+                const_eval = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "fn_traits" {
+                fn_traits = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "no_structs" {
+                no_structs = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "allow_empty" {
+                allow_empty = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "dispatch" {
+                dispatch = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "discriminant" {
+                discriminant = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "name" {
+                name = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "boxed" {
+                boxed = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "call_all" {
+                call_all = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "call_batch" {
+                call_batch = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "catch_unwind" {
+                catch_unwind = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "box_output" {
+                box_output = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "try_output" {
+                let value: Type = meta.value()?.parse()?;
+                try_output = Some(value);
+                return Ok(());
+            }
+            if ident == "error" {
+                let value: Type = meta.value()?.parse()?;
+                error = Some(value);
+                return Ok(());
+            }
+            if ident == "arg_order" {
+                arg_order = Some(parse_arg_order(&meta)?);
+                return Ok(());
+            }
+            if let Some(f) = parse_fn_meta(&meta)? {
+                if func.replace(f).is_some() {
+                    return Err(meta.error("only one 'fn' or 'fn_path' can be bound"));
+                }
+                return Ok(());
+            }
 
-    attr.parse_nested_meta(|meta| {
-        let ident = meta.path.require_ident()?;
-        if ident == "output" {
-            let value = meta.value()?;
-            output = Some(value.parse()?);
-            return Ok(());
+            Err(meta.error(format!("unrecognized attribute for argcall: {}", ident)))
+        })?;
+    }
+    let func = bind_fn(func, arg_order, args)?;
+
+    // The context is passed as the first argument, bound as `ctx`
+    if let Some((ident, ty)) = ctx {
+        call_args.patterns.insert(0, syn::parse_quote! { #ident });
+        call_args.types.insert(0, ty);
+    }
+
+    // `try_output` and `error` are shorthand for a `Result` output
+    match (try_output, error) {
+        (Some(ok), Some(err)) if output.is_none() => {
+            output = Some(quote! { ::core::result::Result<#ok, #err> });
+        }
+        (Some(ok), Some(_)) => {
+            return Err(syn::Error::new_spanned(
+                ok,
+                "'try_output' cannot be combined with 'output'",
+            ));
+        }
+        (Some(ok), None) => {
+            return Err(syn::Error::new_spanned(
+                ok,
+                "'try_output' requires an 'error' attribute",
+            ));
         }
+        (None, Some(err)) => {
+            return Err(syn::Error::new_spanned(
+                err,
+                "'error' requires a 'try_output' attribute",
+            ));
+        }
+        (None, None) => {}
+    }
 
-        Err(meta.error(format!("unrecognized attribute for argcall: {}", ident)))
-    })?;
+    Ok(ContainerAttributes {
+        output,
+        func,
+        args: call_args,
+        dispatch,
+        discriminant,
+        name,
+        boxed,
+        call_all,
+        call_batch,
+        catch_unwind,
+        box_output,
+        enum_output,
+        no_structs,
+        allow_empty,
+        const_eval,
+        fn_traits,
+        struct_vis,
+        struct_suffix,
+        struct_derive,
+        bound,
+        ref_lifetime,
+        krate,
+    })
+}
 
-    output.ok_or_else(|| syn::Error::new_spanned(attr, "expected an 'output' attribute"))
+struct VariantAttributes {
+    output: Option<TokenStream>,
+    func: Option<TokenStream>,
+    /// Set when `func` was given as `async_fn`
+    async_fn: Option<Span>,
+    /// Set when the variant returns the default value of its output instead of a `func`
+    default: Option<Span>,
+    struct_name: Option<Ident>,
+    /// The name used by `dispatch` instead of the identifier
+    rename: Option<LitStr>,
+    /// Set when `dispatch` leaves the variant out
+    skip: Option<Span>,
+    /// How a tuple variant passes its inner value, `"ref"` or `"value"`
+    delegate: Option<LitStr>,
 }
 
-fn parse_fn_attribute(
-    attr: &Attribute,
-    args: impl Iterator<Item = Ident> + Clone,
-) -> Result<proc_macro2::TokenStream, syn::Error> {
-    let mut f = None;
+fn parse_variant_attributes(
+    attrs: &[Attribute],
+    args: impl Iterator<Item = Ident>,
+) -> Result<VariantAttributes, syn::Error> {
+    let mut output = None;
+    let mut func = None;
+    let mut arg_order = None;
+    let mut struct_name = None;
+    let mut rename = None;
+    let mut skip = None;
+    let mut async_fn = None;
+    let mut default = None;
+    let mut delegate = None;
 
-    attr.parse_nested_meta(|meta| {
-        let ident = meta.path.require_ident()?;
-        if ident == "fn" {
-            let value = meta.value()?;
-            f = Some(value.parse()?);
-            return Ok(());
-        }
-        if ident == "fn_path" {
-            let value: LitStr = meta.value()?.parse()?;
-            let ident = Ident::new(&value.value(), value.span());
-            let args = args.clone();
-            f = Some(quote! { #ident(#(#args),*) });
-            return Ok(());
-        }
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("argcall")) {
+        attr.parse_nested_meta(|meta| {
+            let ident = meta.path.require_ident()?;
+            if ident == "output" {
+                output = Some(parse_output(&meta)?.into_token_stream());
+                return Ok(());
+            }
+            if ident == "struct_name" {
+                let value: LitStr = meta.value()?.parse()?;
+                struct_name = Some(value.parse()?);
+                return Ok(());
+            }
+            if ident == "default" {
+                default = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "rename" {
+                rename = Some(meta.value()?.parse()?);
+                return Ok(());
+            }
+            if ident == "skip" {
+                skip = Some(ident.span());
+                return Ok(());
+            }
+            if ident == "delegate" {
+                let value: LitStr = meta.value()?.parse()?;
+                if value.value() != "ref" && value.value() != "value" {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "expected 'delegate' to be \"ref\" or \"value\"",
+                    ));
+                }
+                delegate = Some(value);
+                return Ok(());
+            }
+            if ident == "arg_order" {
+                arg_order = Some(parse_arg_order(&meta)?);
+                return Ok(());
+            }
+            let f = if ident == "async_fn" {
+                let value: Expr = meta.value()?.parse()?;
+                async_fn = Some(ident.span());
+                Some(FnMeta::Expr(value.into_token_stream()))
+            } else {
+                parse_fn_meta(&meta)?
+            };
+            if let Some(f) = f {
+                if func.replace(f).is_some() {
+                    return Err(meta.error("only one 'fn' or 'fn_path' can be bound"));
+                }
+                return Ok(());
+            }
 
-        Err(meta.error(format!("unrecognized attribute for argcall: {}", ident)))
-    })?;
+            Err(meta.error(format!("unrecognized attribute for argcall: {}", ident)))
+        })?;
+    }
+    let func = bind_fn(func, arg_order, args)?;
+
+    if let (Some(span), Some(_)) = (default, &func) {
+        return Err(syn::Error::new(
+            span,
+            "'default' cannot be combined with 'fn' or 'fn_path'",
+        ));
+    }
+
+    Ok(VariantAttributes {
+        output,
+        func,
+        async_fn,
+        default,
+        struct_name,
+        rename,
+        skip,
+        delegate,
+    })
+}
+
+/// A function bound with `fn`, or with `fn_path` whose arguments are added once all keys are parsed
+enum FnMeta {
+    Expr(TokenStream),
+    Path(syn::Path),
+}
+
+/// The error message for a struct or variant without a function to call, listing the keys that
+/// bind one
+fn missing_fn_message(item: &str, allows_default: bool) -> String {
+    let mut keys = vec!["#[argcall(fn = ...)]", "#[argcall(fn_path = \"...\")]"];
+    if allows_default {
+        keys.push("#[argcall(default)]");
+    }
+    let (last, rest) = keys.split_last().unwrap();
+    format!(
+        "expected a function to call on this {item}: add {} or {last}, \
+         e.g. #[argcall(fn = handle())]",
+        rest.join(", ")
+    )
+}
 
-    f.ok_or_else(|| syn::Error::new_spanned(attr, "expected an 'fn' or 'fn_path' attribute"))
+/// Parses `fn = <expr>` or `fn_path = "<function>"`, returning `None` for any other key
+fn parse_fn_meta(meta: &ParseNestedMeta) -> Result<Option<FnMeta>, syn::Error> {
+    let ident = meta.path.require_ident()?;
+    if ident == "fn" {
+        let value: Expr = meta.value()?.parse()?;
+        return Ok(Some(FnMeta::Expr(value.into_token_stream())));
+    }
+    if ident == "fn_path" {
+        let value: LitStr = meta.value()?.parse()?;
+        return Ok(Some(FnMeta::Path(value.parse()?)));
+    }
+    Ok(None)
+}
+
+/// Parses `output = <Type>`, or `output = "<Type>"` as `fn_path` takes its path as a string
+fn parse_output(meta: &ParseNestedMeta) -> Result<Type, syn::Error> {
+    let input = meta.value()?;
+    if input.peek(LitStr) {
+        return input.parse::<LitStr>()?.parse();
+    }
+    input.parse()
+}
+
+/// Parses `arg_order = [<field>, ...]`, where `self` may be listed to pass the receiver
+fn parse_arg_order(meta: &ParseNestedMeta) -> Result<(Span, Vec<Ident>), syn::Error> {
+    let span = meta.path.span();
+    let input = meta.value()?;
+    let content;
+    syn::bracketed!(content in input);
+    let names = Punctuated::<Ident, Token![,]>::parse_terminated_with(&content, Ident::parse_any)?;
+    Ok((span, names.into_iter().collect()))
+}
+
+/// Turns the bound function into an expression. `fn_path` passes the fields in declaration order,
+/// or in the order given by `arg_order`.
+fn bind_fn(
+    func: Option<FnMeta>,
+    arg_order: Option<(Span, Vec<Ident>)>,
+    args: impl Iterator<Item = Ident>,
+) -> Result<Option<TokenStream>, syn::Error> {
+    match (func, arg_order) {
+        (Some(FnMeta::Path(path)), Some((_, order))) => {
+            let args = args.collect::<Vec<_>>();
+            if let Some(unknown) = order
+                .iter()
+                .find(|name| *name != "self" && !args.contains(name))
+            {
+                return Err(syn::Error::new(
+                    unknown.span(),
+                    format!("'arg_order' names unknown field `{}`", unknown),
+                ));
+            }
+            Ok(Some(quote! { #path(#(#order),*) }))
+        }
+        (Some(FnMeta::Path(path)), None) => Ok(Some(quote! { #path(#(#args),*) })),
+        (_, Some((span, _))) => Err(syn::Error::new(span, "'arg_order' requires 'fn_path'")),
+        (Some(FnMeta::Expr(expr)), None) => Ok(Some(expr)),
+        (None, None) => Ok(None),
+    }
 }
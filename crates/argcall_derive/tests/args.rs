@@ -0,0 +1,96 @@
+use argcall::{Callable, CallableMut};
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (n: i32))]
+enum Op {
+    #[argcall(fn = n * 2)]
+    Double,
+    #[argcall(fn = n + offset)]
+    Offset {
+        offset: i32,
+    },
+    Inner(Negate),
+}
+
+struct Negate;
+
+impl Callable<(i32,)> for Negate {
+    type Output = i32;
+
+    fn call_fn(&self, (n,): (i32,)) -> Self::Output {
+        -n
+    }
+}
+
+#[derive(Callable)]
+#[argcall(output = String, args = (name: &'static str, count: usize))]
+enum Greeting {
+    #[argcall(fn = format!("hello {}", name).repeat(count))]
+    Hello,
+    #[argcall(fn_path = "greet")]
+    Custom { greeting: String },
+}
+
+fn greet(greeting: &str) -> String {
+    greeting.to_string()
+}
+
+#[derive(CallableMut)]
+#[argcall(output = i32, args = (step: i32), fn = { *total += step; *total })]
+struct Accumulator {
+    total: i32,
+}
+
+#[test]
+fn test_enum_args() {
+    assert_eq!(Op::Double.call_fn((21,)), 42);
+    assert_eq!(Op::Offset { offset: 1 }.call_fn((2,)), 3);
+    assert_eq!(Op::Inner(Negate).call_fn((5,)), -5);
+    assert_eq!(OpDoubleCallable.call_fn((4,)), 8);
+}
+
+#[test]
+fn test_enum_multiple_args() {
+    assert_eq!(
+        Greeting::Hello.call_fn(("world", 2)),
+        "hello worldhello world"
+    );
+    let custom = Greeting::Custom {
+        greeting: "hi".to_string(),
+    };
+    assert_eq!(custom.call_fn(("unused", 0)), "hi");
+}
+
+#[test]
+fn test_struct_args() {
+    let mut acc = Accumulator { total: 0 };
+    assert_eq!(acc.call_fn_mut((2,)), 2);
+    assert_eq!(acc.call_fn_mut((3,)), 5);
+}
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = ((x, y): (i32, i32), Point { x: px, .. }: Point, _: u8))]
+enum Pattern {
+    #[argcall(fn = x * y + px)]
+    Combine,
+    #[argcall(fn = (x, y).0)]
+    First,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = ((a, (b, c)): (i32, (i32, i32))), fn = a + b * c)]
+struct Nested;
+
+#[test]
+fn test_pattern_args() {
+    let point = || Point { x: 10, y: 0 };
+    assert_eq!(point().y, 0);
+    assert_eq!(Pattern::Combine.call_fn(((2, 3), point(), 0)), 16);
+    assert_eq!(Pattern::First.call_fn(((2, 3), point(), 0)), 2);
+    assert_eq!(Nested.call_fn(((1, (2, 3)),)), 7);
+}
@@ -0,0 +1,62 @@
+use argcall::{AsyncCallable, AsyncCallableOnce};
+use pollster::FutureExt as _;
+
+#[derive(AsyncCallable)]
+#[argcall(output = i32)]
+enum Fetch {
+    #[argcall(fn = one())]
+    One,
+    #[argcall(fn_path = "one")]
+    OnePath,
+    #[argcall(fn = double(*x))]
+    Copied {
+        x: i32,
+    },
+    Inner(Nested),
+}
+
+#[derive(AsyncCallable)]
+#[argcall(output = String, args = (name: String))]
+enum Greet {
+    #[argcall(fn = async move { format!("hello {}", name) })]
+    Hello,
+}
+
+#[derive(AsyncCallable)]
+#[argcall(output = i32, fn = double(*_0))]
+struct Doubler(i32);
+
+#[derive(AsyncCallable)]
+#[argcall(output = i32)]
+enum Nested {
+    #[argcall(fn = one())]
+    One,
+}
+
+async fn one() -> i32 {
+    1
+}
+
+async fn double(x: i32) -> i32 {
+    x * 2
+}
+
+#[test]
+fn test_async_enum() {
+    assert_eq!(Fetch::One.call_fn_async(()).block_on(), 1);
+    assert_eq!(Fetch::OnePath.call_fn_async(()).block_on(), 1);
+    assert_eq!(Fetch::Copied { x: 3 }.call_fn_async(()).block_on(), 6);
+    assert_eq!(Fetch::Inner(Nested::One).call_fn_async(()).block_on(), 1);
+    assert_eq!(FetchOneCallable.call_fn_async(()).block_on(), 1);
+}
+
+#[test]
+fn test_async_args() {
+    let greeting = Greet::Hello.call_fn_async(("argcall".to_string(),));
+    assert_eq!(greeting.block_on(), "hello argcall");
+}
+
+#[test]
+fn test_async_struct() {
+    assert_eq!(Doubler(21).call_fn_async_once(()).block_on(), 42);
+}
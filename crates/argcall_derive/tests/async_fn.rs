@@ -0,0 +1,106 @@
+use argcall::{AsyncCallable, Callable};
+use pollster::FutureExt as _;
+
+#[derive(Callable)]
+#[argcall(output = usize, args = (n: usize))]
+enum Task {
+    #[argcall(fn = n + 1)]
+    Sync,
+    #[argcall(async_fn = slow_double(n))]
+    Async,
+    #[argcall(fn = name.len() + n)]
+    Named {
+        name: String,
+    },
+    #[argcall(async_fn = slow_double(*_0 + n))]
+    Tuple(usize),
+    Inner(Offset),
+    #[argcall(output = u8, fn = 7u8)]
+    Converted,
+}
+
+async fn slow_double(n: usize) -> usize {
+    n * 2
+}
+
+struct Offset(usize);
+
+impl Callable<(usize,)> for Offset {
+    type Output = usize;
+    fn call_fn(&self, (n,): (usize,)) -> Self::Output {
+        self.0 + n
+    }
+}
+
+#[derive(AsyncCallable)]
+#[argcall(output = usize)]
+enum Explicit {
+    #[argcall(async_fn = slow_double(1))]
+    Async,
+}
+
+#[test]
+fn test_sync_and_async_variants() {
+    assert_eq!(Task::Sync.call_fn_async((1,)).block_on(), 2);
+    assert_eq!(Task::Async.call_fn_async((3,)).block_on(), 6);
+    assert_eq!(
+        Task::Named {
+            name: "ab".to_string()
+        }
+        .call_fn_async((1,))
+        .block_on(),
+        3
+    );
+    assert_eq!(Task::Tuple(2).call_fn_async((1,)).block_on(), 6);
+    assert_eq!(Task::Inner(Offset(10)).call_fn_async((1,)).block_on(), 11);
+    assert_eq!(Task::Converted.call_fn_async((0,)).block_on(), 7);
+}
+
+#[test]
+fn test_variant_structs() {
+    assert_eq!(TaskSyncCallable.call_fn_async((1,)).block_on(), 2);
+    assert_eq!(TaskAsyncCallable.call_fn_async((2,)).block_on(), 4);
+    let converted: u8 = TaskConvertedCallable.call_fn_async((0,)).block_on();
+    assert_eq!(converted, 7);
+}
+
+#[test]
+fn test_async_fn_on_async_callable() {
+    assert_eq!(Explicit::Async.call_fn_async(()).block_on(), 2);
+}
+
+async fn fetch(id: u64) -> String {
+    format!("user {}", id)
+}
+
+#[derive(AsyncCallable)]
+#[argcall(output = String, args = (id: u64))]
+enum Lookup {
+    #[argcall(async_fn = fetch(id))]
+    User,
+    #[argcall(async_fn = fetch(id + *offset))]
+    Offset { offset: u64 },
+}
+
+#[derive(Callable)]
+#[argcall(output = String, args = (id: u64))]
+enum CachedLookup {
+    #[argcall(fn = "cached".to_string())]
+    Cached,
+    #[argcall(async_fn = fetch(id))]
+    Remote,
+}
+
+#[test]
+fn test_async_fn_with_args() {
+    pollster::block_on(async {
+        assert_eq!(Lookup::User.call_fn_async((7,)).await, "user 7");
+        assert_eq!(
+            Lookup::Offset { offset: 3 }.call_fn_async((7,)).await,
+            "user 10"
+        );
+        assert_eq!(LookupUserCallable.call_fn_async((7,)).await, "user 7");
+        assert_eq!(CachedLookup::Cached.call_fn_async((7,)).await, "cached");
+        assert_eq!(CachedLookup::Remote.call_fn_async((7,)).await, "user 7");
+    });
+}
@@ -0,0 +1,135 @@
+use argcall::{Callable, CallableMut, CallableOnce};
+
+#[derive(Callable)]
+#[argcall(output = usize)]
+enum Borrowed {
+    #[argcall(fn = name.len())]
+    Named { name: String },
+    #[argcall(fn = _0.len() + _1.len())]
+    Tuple(String, Vec<u8>),
+}
+
+#[derive(CallableMut)]
+#[argcall(output = String)]
+enum Appending {
+    #[argcall(fn = { log.push('!'); log.clone() })]
+    Named { log: String },
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = String)]
+enum Moving {
+    #[argcall(fn = name)]
+    Named { name: String },
+    #[argcall(fn = shout(greeting, name))]
+    Consumed { greeting: String, name: String },
+    #[argcall(fn_path = "join")]
+    Joined { parts: Vec<String> },
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = usize)]
+enum Drain {
+    #[argcall(fn = bytes.into_iter().filter(|byte| *byte != 0).count())]
+    Bytes { bytes: Vec<u8> },
+    #[argcall(fn = { bytes.extend(extra); bytes.len() })]
+    Extended { bytes: Vec<u8>, extra: Vec<u8> },
+}
+
+fn shout(mut greeting: String, name: String) -> String {
+    greeting.push_str(&name.to_uppercase());
+    greeting
+}
+
+fn join(parts: Vec<String>) -> String {
+    parts.into_iter().collect()
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = Vec<String>, fn = { items.push(last); items })]
+struct Consuming {
+    items: Vec<String>,
+    last: String,
+}
+
+#[derive(CallableMut)]
+#[argcall(output = i64, args = (amount: i64))]
+enum Account {
+    #[argcall(fn = { *balance += amount; *balance })]
+    Deposit { balance: i64 },
+    #[argcall(fn = { _0.push(amount); _0.iter().sum() })]
+    History(Vec<i64>),
+}
+
+#[derive(CallableMut)]
+#[argcall(output = usize, fn = { items.push(items.len()); items.len() })]
+struct Items {
+    items: Vec<usize>,
+}
+
+#[test]
+fn test_fields_are_borrowed() {
+    let named = Borrowed::Named {
+        name: "argcall".to_string(),
+    };
+    assert_eq!(named.call_fn(()), 7);
+    assert_eq!(named.call_fn(()), 7);
+    assert_eq!(Borrowed::Tuple("ab".to_string(), vec![1]).call_fn(()), 3);
+}
+
+#[test]
+fn test_fields_are_borrowed_mutably() {
+    let mut appending = Appending::Named { log: String::new() };
+    appending.call_fn_mut(());
+    assert_eq!(appending.call_fn_mut(()), "!!");
+
+    let mut account = Account::Deposit { balance: 0 };
+    let totals: Vec<i64> = [5, 10, -3]
+        .map(|amount| account.call_fn_mut((amount,)))
+        .into();
+    assert_eq!(totals, [5, 15, 12]);
+    let mut history = Account::History(Vec::new());
+    history.call_fn_mut((1,));
+    assert_eq!(history.call_fn_mut((2,)), 3);
+
+    let mut items = Items { items: Vec::new() };
+    items.call_fn_mut(());
+    assert_eq!(items.call_fn_mut(()), 2);
+}
+
+#[test]
+fn test_named_fields_are_consumed() {
+    let bytes = Drain::Bytes {
+        bytes: vec![1, 0, 3],
+    };
+    assert_eq!(bytes.call_fn_once(()), 2);
+    let extended = Drain::Extended {
+        bytes: vec![1],
+        extra: vec![2, 3, 4],
+    };
+    assert_eq!(extended.call_fn_once(()), 4);
+}
+
+#[test]
+fn test_fields_are_moved() {
+    let moving = Moving::Named {
+        name: "moved".to_string(),
+    };
+    assert_eq!(moving.call_fn_once(()), "moved");
+
+    let consumed = Moving::Consumed {
+        greeting: "hello ".to_string(),
+        name: "argcall".to_string(),
+    };
+    assert_eq!(consumed.call_fn_once(()), "hello ARGCALL");
+    let joined = Moving::Joined {
+        parts: vec!["a".to_string(), "b".to_string()],
+    };
+    assert_eq!(joined.call_fn_once(()), "ab");
+
+    let consuming = Consuming {
+        items: vec!["x".to_string()],
+        last: "y".to_string(),
+    };
+    assert_eq!(consuming.call_fn_once(()), ["x", "y"]);
+}
@@ -0,0 +1,61 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (n: i32))]
+enum Compute {
+    #[argcall(fn = {
+        let doubled = n * 2;
+        let offset = 1;
+        doubled + offset
+    })]
+    Block,
+    #[argcall(fn = {
+        if n < 0 {
+            return 0;
+        }
+        let mut total = 0;
+        for i in 0..=n {
+            total += i;
+        }
+        total
+    })]
+    Sum,
+    #[argcall(fn = {
+        let values: Vec<i32> = values.iter().map(|v| v * n).collect();
+        values.iter().sum()
+    })]
+    Scaled { values: Vec<i32> },
+}
+
+#[derive(Callable)]
+#[argcall(output = String, fn = {
+    let mut name = name.clone();
+    name.push('!');
+    name
+})]
+struct Shout {
+    name: String,
+}
+
+#[test]
+fn test_block_bodies() {
+    assert_eq!(Compute::Block.call_fn((3,)), 7);
+    assert_eq!(Compute::Sum.call_fn((4,)), 10);
+    assert_eq!(Compute::Sum.call_fn((-1,)), 0);
+    assert_eq!(ComputeSumCallable.call_fn((3,)), 6);
+    assert_eq!(
+        Compute::Scaled {
+            values: vec![1, 2, 3]
+        }
+        .call_fn((2,)),
+        12
+    );
+}
+
+#[test]
+fn test_block_body_on_struct() {
+    let shout = Shout {
+        name: "argcall".to_string(),
+    };
+    assert_eq!(shout.call_fn(()), "argcall!");
+}
@@ -0,0 +1,46 @@
+use argcall::Callable;
+use std::fmt::Display;
+
+#[derive(Callable)]
+#[argcall(output = String, bound = "T: Display")]
+enum E<T> {
+    #[argcall(fn = format!("<{}>", _0))]
+    Show(T),
+    #[argcall(fn = String::new())]
+    Empty,
+}
+
+#[derive(Callable)]
+#[argcall(output = String, bound = "T: Display, U: Display + Clone")]
+enum Pair<T, U>
+where
+    T: Clone,
+{
+    #[argcall(fn = format!("{}{}", first, second.clone()))]
+    Both { first: T, second: U },
+}
+
+#[derive(Callable)]
+#[argcall(output = String, fn = self.0.to_string(), bound = "T: Display")]
+struct Wrapper<T>(T);
+
+struct NotDisplay;
+
+#[test]
+fn test_bound() {
+    assert_eq!(E::Show(5).call_fn(()), "<5>");
+    assert_eq!(E::<u8>::Empty.call_fn(()), "");
+    assert_eq!(EEmptyCallable::<u8>(Default::default()).call_fn(()), "");
+    assert_eq!(
+        Pair::Both {
+            first: 'a',
+            second: "b"
+        }
+        .call_fn(()),
+        "ab"
+    );
+    assert_eq!(Wrapper(1.5).call_fn(()), "1.5");
+
+    // The bound applies to the impl only, not to the type itself
+    let _ = E::Show(NotDisplay);
+}
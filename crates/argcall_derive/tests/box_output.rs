@@ -0,0 +1,59 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = Box<dyn Iterator<Item = i32>>, args = (n: i32), box_output)]
+enum Numbers {
+    #[argcall(fn = 0..n)]
+    Range,
+    #[argcall(fn = (0..n).map(|i| i * 2))]
+    Doubled,
+    #[argcall(fn = values.clone().into_iter().filter(move |v| *v < n))]
+    Below {
+        values: Vec<i32>,
+    },
+    #[argcall(fn = core::iter::repeat_n(*_0, n as usize))]
+    Repeat(i32),
+    Inner(Evens),
+}
+
+struct Evens;
+
+impl Callable<(i32,)> for Evens {
+    type Output = Box<dyn Iterator<Item = i32>>;
+
+    fn call_fn(&self, (n,): (i32,)) -> Self::Output {
+        Box::new((0..n).filter(|i| i % 2 == 0))
+    }
+}
+
+#[derive(Callable)]
+#[argcall(output = Box<dyn Fn(i32) -> i32>, fn = { let offset = *offset; move |x| x + offset }, box_output)]
+struct Adder {
+    offset: i32,
+}
+
+#[test]
+fn test_box_output_enum() {
+    let collect = |numbers: Numbers| numbers.call_fn((4,)).collect::<Vec<_>>();
+    assert_eq!(collect(Numbers::Range), [0, 1, 2, 3]);
+    assert_eq!(collect(Numbers::Doubled), [0, 2, 4, 6]);
+    assert_eq!(
+        collect(Numbers::Below {
+            values: vec![5, 1, 3, 7]
+        }),
+        [1, 3]
+    );
+    assert_eq!(collect(Numbers::Repeat(9)), [9, 9, 9, 9]);
+    assert_eq!(collect(Numbers::Inner(Evens)), [0, 2]);
+}
+
+#[test]
+fn test_box_output_variant_struct() {
+    assert_eq!(NumbersDoubledCallable.call_fn((3,)).sum::<i32>(), 6);
+}
+
+#[test]
+fn test_box_output_struct() {
+    let add = Adder { offset: 2 }.call_fn(());
+    assert_eq!(add(40), 42);
+}
@@ -0,0 +1,37 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, boxed)]
+enum Op {
+    #[argcall(fn = 1)]
+    One,
+    #[argcall(fn = 2)]
+    Two,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (x: i32), boxed)]
+enum Scale {
+    #[argcall(fn = x * 2)]
+    Double,
+    #[argcall(fn = -x)]
+    Negate,
+}
+
+#[test]
+fn test_boxed_variants() {
+    let one: Box<dyn Callable<Output = i32>> = (&Op::One).into();
+    assert_eq!(one.call_fn(()), 1);
+    let two = Box::<dyn Callable<Output = i32>>::from(&Op::Two);
+    assert_eq!(two.call_fn(()), 2);
+}
+
+#[test]
+fn test_boxed_with_args() {
+    let callables: Vec<Box<dyn Callable<(i32,), Output = i32>>> = [Scale::Double, Scale::Negate]
+        .iter()
+        .map(Into::into)
+        .collect();
+    let results: Vec<i32> = callables.iter().map(|c| c.call_fn((21,))).collect();
+    assert_eq!(results, [42, -21]);
+}
@@ -0,0 +1,41 @@
+use argcall::{Callable, CallableMut};
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (x: i32), catch_unwind)]
+enum Sandboxed {
+    #[argcall(fn = x * 2)]
+    Double,
+    #[argcall(fn = panic!("boom"))]
+    Panic,
+    #[argcall(fn = 100 / *divisor)]
+    Divide { divisor: i32 },
+}
+
+#[derive(CallableMut)]
+#[argcall(output = u32, fn = { *count += 1; assert!(*count < 3); *count }, catch_unwind)]
+struct Limited {
+    count: u32,
+}
+
+#[test]
+fn test_panic_is_caught() {
+    assert_eq!(Sandboxed::Double.call_fn((21,)).unwrap(), 42);
+    let payload = Sandboxed::Panic.call_fn((1,)).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+    assert_eq!(Sandboxed::Divide { divisor: 5 }.call_fn((0,)).unwrap(), 20);
+    assert!(Sandboxed::Divide { divisor: 0 }.call_fn((0,)).is_err());
+}
+
+#[test]
+fn test_variant_struct_catches_panic() {
+    assert_eq!(SandboxedDoubleCallable.call_fn((2,)).unwrap(), 4);
+    assert!(SandboxedPanicCallable.call_fn((2,)).is_err());
+}
+
+#[test]
+fn test_struct_catches_panic() {
+    let mut limited = Limited { count: 0 };
+    assert_eq!(limited.call_fn_mut(()).unwrap(), 1);
+    assert_eq!(limited.call_fn_mut(()).unwrap(), 2);
+    assert!(limited.call_fn_mut(()).is_err());
+}
@@ -0,0 +1,29 @@
+use argcall::Callable;
+
+// The derive receives the enum with the disabled variants removed, so neither their structs nor
+// their match arms are generated
+#[derive(Callable, Debug, PartialEq)]
+#[argcall(output = i32, dispatch, call_all)]
+enum Gated {
+    #[argcall(fn = 1)]
+    Always,
+    #[cfg(not(test))]
+    #[argcall(fn = missing())]
+    Never,
+    #[cfg(test)]
+    #[argcall(fn = 3)]
+    Enabled,
+    #[cfg_attr(test, argcall(fn = 4))]
+    #[cfg_attr(not(test), argcall(fn = missing()))]
+    Attr,
+}
+
+#[test]
+fn test_cfg_variants() {
+    assert_eq!(Gated::from_name("Never"), None);
+    assert_eq!(Gated::all(), [Gated::Always, Gated::Enabled, Gated::Attr]);
+    assert_eq!(Gated::Enabled.call_fn(()), 3);
+    assert_eq!(GatedEnabledCallable.call_fn(()), 3);
+    assert_eq!(Gated::Attr.call_fn(()), 4);
+    assert_eq!(Gated::call_all(), [1, 3, 4]);
+}
@@ -0,0 +1,55 @@
+use argcall::Callable;
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum, Callable)]
+#[argcall(output = i32, args = (value: i32))]
+enum Op {
+    #[argcall(fn = value * 2)]
+    Double,
+    #[argcall(fn = -value)]
+    Negate,
+}
+
+#[derive(Debug, Subcommand, Callable)]
+#[argcall(output = String)]
+enum Command {
+    /// Applies an operation to a value
+    #[argcall(fn = op.call_fn((*value,)).to_string())]
+    Apply {
+        #[arg(value_enum)]
+        op: Op,
+        #[arg(long, allow_negative_numbers = true)]
+        value: i32,
+    },
+    /// Repeats a word
+    #[argcall(fn = word.repeat(*count))]
+    Repeat {
+        word: String,
+        #[arg(short, long, default_value_t = 1)]
+        count: usize,
+    },
+}
+
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[test]
+fn test_value_enum() {
+    let op = Op::from_str("negate", false).unwrap();
+    assert_eq!(op, Op::Negate);
+    assert_eq!(op.call_fn((3,)), -3);
+    assert_eq!(Op::from_str("double", false).unwrap().call_fn((3,)), 6);
+}
+
+#[test]
+fn test_subcommand() {
+    let cli = Cli::parse_from(["app", "apply", "double", "--value", "21"]);
+    assert_eq!(cli.command.call_fn(()), "42");
+    let cli = Cli::parse_from(["app", "repeat", "ab", "-c", "3"]);
+    assert_eq!(cli.command.call_fn(()), "ababab");
+    let cli = Cli::parse_from(["app", "repeat", "ab"]);
+    assert_eq!(cli.command.call_fn(()), "ab");
+}
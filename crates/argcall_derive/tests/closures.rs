@@ -0,0 +1,70 @@
+use argcall::{Callable, CallableMut, CallableOnce};
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (n: i32))]
+enum Stored {
+    #[argcall(fn = (f)())]
+    Thunk { f: Box<dyn Fn() -> i32> },
+    #[argcall(fn = (f)(n) + offset)]
+    Unary {
+        f: Box<dyn Fn(i32) -> i32>,
+        offset: i32,
+    },
+    #[argcall(fn = _0(n))]
+    Pointer(fn(i32) -> i32),
+}
+
+#[derive(CallableMut)]
+#[argcall(output = u32)]
+enum StoredMut {
+    #[argcall(fn = (f)())]
+    Counter { f: Box<dyn FnMut() -> u32> },
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = String)]
+enum StoredOnce {
+    #[argcall(fn = (f)())]
+    Consume { f: Box<dyn FnOnce() -> String> },
+}
+
+#[derive(Callable)]
+#[argcall(output = usize, fn = (f)(name))]
+struct Generic<F: Fn(&str) -> usize> {
+    name: String,
+    f: F,
+}
+
+#[test]
+fn test_boxed_closure_fields() {
+    let thunk = Stored::Thunk { f: Box::new(|| 42) };
+    assert_eq!(thunk.call_fn((0,)), 42);
+    let unary = Stored::Unary {
+        f: Box::new(|n| n * 2),
+        offset: 1,
+    };
+    assert_eq!(unary.call_fn((20,)), 41);
+    assert_eq!(Stored::Pointer(i32::abs).call_fn((-3,)), 3);
+
+    let mut count = 0;
+    let mut counter = StoredMut::Counter {
+        f: Box::new(move || {
+            count += 1;
+            count
+        }),
+    };
+    assert_eq!(counter.call_fn_mut(()), 1);
+    assert_eq!(counter.call_fn_mut(()), 2);
+
+    let text = "owned".to_string();
+    let consume = StoredOnce::Consume {
+        f: Box::new(move || text),
+    };
+    assert_eq!(consume.call_fn_once(()), "owned");
+
+    let generic = Generic {
+        name: "four".to_string(),
+        f: str::len,
+    };
+    assert_eq!(generic.call_fn(()), 4);
+}
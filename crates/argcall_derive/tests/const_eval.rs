@@ -0,0 +1,34 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = u32, const)]
+enum Size {
+    #[argcall(fn = 1)]
+    Small,
+    #[argcall(fn = double(8))]
+    Large,
+    #[argcall(fn = *n)]
+    Custom { n: u32 },
+}
+
+const fn double(n: u32) -> u32 {
+    n * 2
+}
+
+#[derive(Callable)]
+#[argcall(output = u32, args = (n: u32), fn = self.0 * n, const)]
+struct Scale(u32);
+
+const SMALL: u32 = Size::Small.eval(());
+const LARGE: u32 = Size::Large.eval(());
+const CUSTOM: u32 = Size::Custom { n: 5 }.eval(());
+const SCALED: u32 = Scale(3).eval((4,));
+
+#[test]
+fn test_const_eval() {
+    assert_eq!(SMALL, 1);
+    assert_eq!(LARGE, 16);
+    assert_eq!(CUSTOM, 5);
+    assert_eq!(SCALED, 12);
+    assert_eq!(Size::Large.eval(()), Size::Large.call_fn(()));
+}
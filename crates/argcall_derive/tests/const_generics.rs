@@ -0,0 +1,47 @@
+use argcall::Callable;
+use core::marker::PhantomData;
+
+#[derive(Callable)]
+#[argcall(output = [u8; N])]
+enum Buffer<const N: usize> {
+    #[argcall(fn = [0; N])]
+    Zeroed,
+    #[argcall(fn = [*byte; N])]
+    Filled { byte: u8 },
+}
+
+#[test]
+fn test_const_generic_output() {
+    assert_eq!(Buffer::<4>::Zeroed.call_fn(()), [0; 4]);
+    assert_eq!(Buffer::<2>::Filled { byte: 7 }.call_fn(()), [7, 7]);
+    assert_eq!(BufferZeroedCallable::<3>.call_fn(()), [0; 3]);
+}
+
+#[derive(Callable)]
+#[argcall(output = [T; N], args = (value: T))]
+enum Splat<T: Copy + Default, const N: usize> {
+    #[argcall(fn = [value; N])]
+    Value,
+    #[argcall(fn = [T::default(); N])]
+    Default(PhantomData<T>),
+}
+
+#[derive(Callable)]
+#[argcall(output = [u8; N], fn = { let mut out = [0; N]; out[..M].copy_from_slice(&prefix[..]); out })]
+struct Padded<const N: usize, const M: usize> {
+    prefix: [u8; M],
+}
+
+#[test]
+fn test_const_and_type_generics() {
+    assert_eq!(Splat::<u8, 3>::Value.call_fn((5,)), [5, 5, 5]);
+    assert_eq!(Splat::<i32, 2>::Default(PhantomData).call_fn((5,)), [0, 0]);
+    let value: [u8; 2] = SplatValueCallable::<u8, 2>(PhantomData).call_fn((1,));
+    assert_eq!(value, [1, 1]);
+}
+
+#[test]
+fn test_const_generic_struct() {
+    let padded = Padded::<4, 2> { prefix: [1, 2] };
+    assert_eq!(padded.call_fn(()), [1, 2, 0, 0]);
+}
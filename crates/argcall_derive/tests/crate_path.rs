@@ -0,0 +1,58 @@
+// The module `argcall` shadows the crate in `renamed`, as if the crate had been renamed in
+// `Cargo.toml`, so the generated code only compiles by going through `crate = ...`
+mod renamed {
+    use ::argcall as callables;
+    use callables::{AsyncCallable, Callable, CallableMut};
+    use pollster::FutureExt as _;
+
+    #[allow(dead_code)]
+    mod argcall {}
+
+    #[derive(Callable, Debug, PartialEq)]
+    #[argcall(crate = callables, output = i32, args = (n: i32), dispatch, call_all, boxed)]
+    pub enum Op {
+        #[argcall(fn = n + 1)]
+        Increment,
+        #[argcall(fn = n * 2)]
+        Double,
+    }
+
+    #[derive(Callable)]
+    #[argcall(crate = ::argcall, output = Box<dyn Iterator<Item = i32>>, box_output)]
+    pub enum Numbers {
+        #[argcall(fn = 0..2)]
+        Range,
+        #[argcall(fn = vec![5].into_iter())]
+        List,
+    }
+
+    #[derive(CallableMut)]
+    #[argcall(crate = callables, output = u32, catch_unwind, fn = { *count += 1; *count })]
+    pub struct Counter {
+        pub count: u32,
+    }
+
+    #[derive(AsyncCallable)]
+    #[argcall(crate = callables, output = usize, args = (n: usize))]
+    pub enum Task {
+        #[argcall(fn = async move { n + 1 })]
+        Next,
+    }
+
+    #[test]
+    fn test_crate_path() {
+        assert_eq!(Op::Increment.call_fn((1,)), 2);
+        assert_eq!(OpDoubleCallable.call_fn((3,)), 6);
+        assert_eq!(Op::call_all((5,)), [6, 10]);
+        let boxed: Box<dyn Callable<(i32,), Output = i32>> = (&Op::Double).into();
+        assert_eq!(boxed.call_fn((4,)), 8);
+
+        assert_eq!(Numbers::Range.call_fn(()).collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(NumbersListCallable.call_fn(()).collect::<Vec<_>>(), [5]);
+
+        let mut counter = Counter { count: 0 };
+        assert_eq!(counter.call_fn_mut(()).ok(), Some(1));
+
+        assert_eq!(Task::Next.call_fn_async((1,)).block_on(), 2);
+    }
+}
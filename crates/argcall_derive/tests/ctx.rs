@@ -0,0 +1,57 @@
+use argcall::Callable;
+
+struct Context {
+    user: String,
+    scale: u32,
+}
+
+#[derive(Callable)]
+#[argcall(output = String, ctx = &Context)]
+enum Greeting {
+    #[argcall(fn = format!("hello {}", ctx.user))]
+    Hello,
+    #[argcall(fn = format!("{} {}", word, ctx.user))]
+    Custom { word: String },
+}
+
+#[derive(Callable)]
+#[argcall(output = u32, args = (n: u32), ctx = &Context)]
+enum Scaled {
+    #[argcall(fn = n * ctx.scale)]
+    Scale,
+    #[argcall(fn = n + ctx.user.len() as u32)]
+    Offset,
+    Inner(Doubled),
+}
+
+#[derive(Callable)]
+#[argcall(output = u32, ctx = &Context, args = (n: u32), fn = n * ctx.scale * 2)]
+struct Doubled;
+
+#[test]
+fn test_ctx_in_variants() {
+    let ctx = Context {
+        user: "argcall".to_string(),
+        scale: 3,
+    };
+    assert_eq!(Greeting::Hello.call_fn((&ctx,)), "hello argcall");
+    assert_eq!(
+        Greeting::Custom {
+            word: "bye".to_string()
+        }
+        .call_fn((&ctx,)),
+        "bye argcall"
+    );
+    assert_eq!(GreetingHelloCallable.call_fn((&ctx,)), "hello argcall");
+}
+
+#[test]
+fn test_ctx_before_args() {
+    let ctx = Context {
+        user: "ab".to_string(),
+        scale: 3,
+    };
+    assert_eq!(Scaled::Scale.call_fn((&ctx, 2)), 6);
+    assert_eq!(Scaled::Offset.call_fn((&ctx, 2)), 4);
+    assert_eq!(Scaled::Inner(Doubled).call_fn((&ctx, 2)), 12);
+}
@@ -0,0 +1,47 @@
+use argcall::{AsyncCallable, Callable};
+use pollster::FutureExt as _;
+
+#[derive(Callable)]
+#[argcall(output = Vec<i32>)]
+enum Numbers {
+    #[argcall(default)]
+    Empty,
+    #[argcall(fn = vec![1, 2, 3])]
+    Some,
+    #[argcall(default)]
+    Ignored(u8),
+}
+
+#[derive(Callable)]
+#[argcall(output = u64)]
+enum Converted {
+    #[argcall(output = u8, default)]
+    Small,
+}
+
+#[derive(AsyncCallable)]
+#[argcall(output = String)]
+enum Text {
+    #[argcall(default)]
+    Empty,
+}
+
+#[test]
+fn test_default_variant() {
+    assert_eq!(Numbers::Empty.call_fn(()), Vec::<i32>::new());
+    assert_eq!(Numbers::Some.call_fn(()), vec![1, 2, 3]);
+    assert!(Numbers::Ignored(1).call_fn(()).is_empty());
+    assert!(NumbersEmptyCallable.call_fn(()).is_empty());
+}
+
+#[test]
+fn test_default_variant_output() {
+    assert_eq!(Converted::Small.call_fn(()), 0);
+    let small: u8 = ConvertedSmallCallable.call_fn(());
+    assert_eq!(small, 0);
+}
+
+#[test]
+fn test_default_async_variant() {
+    assert_eq!(Text::Empty.call_fn_async(()).block_on(), "");
+}
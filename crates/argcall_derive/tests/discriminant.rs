@@ -0,0 +1,61 @@
+use argcall::Callable;
+
+#[derive(Callable, Clone, Copy)]
+#[argcall(output = &'static str, discriminant)]
+enum Code {
+    #[argcall(fn = "ok")]
+    Ok = 200,
+    #[argcall(fn = "created")]
+    Created,
+    #[argcall(fn = "not found")]
+    NotFound = 404,
+}
+
+const BASE: i16 = -10;
+
+#[derive(Callable)]
+#[repr(i16)]
+#[argcall(output = i32, discriminant)]
+enum WithFields {
+    #[argcall(fn = 0)]
+    Unit = BASE,
+    #[argcall(fn = *_0)]
+    Tuple(i32),
+    #[argcall(fn = *x)]
+    Named { x: i32 } = 7,
+}
+
+#[test]
+fn test_discriminant() {
+    assert_eq!(Code::Ok.discriminant(), 200);
+    assert_eq!(Code::Created.discriminant(), 201);
+    assert_eq!(Code::NotFound.discriminant(), 404);
+    assert_eq!(Code::NotFound.discriminant(), Code::NotFound as isize);
+    assert_eq!(Code::NotFound.call_fn(()), "not found");
+}
+
+#[test]
+fn test_discriminant_with_fields() {
+    assert_eq!(WithFields::Unit.discriminant(), -10);
+    assert_eq!(WithFields::Tuple(3).discriminant(), -9);
+    assert_eq!(WithFields::Named { x: 1 }.discriminant(), 7);
+    const NAMED: i16 = WithFields::Named { x: 1 }.discriminant();
+    assert_eq!(NAMED, 7);
+}
+
+#[derive(Callable)]
+#[repr(C, u8)]
+#[argcall(output = u8, discriminant)]
+enum Implicit {
+    #[argcall(fn = 0)]
+    First,
+    #[argcall(fn = *_0)]
+    Second(u8),
+}
+
+#[test]
+fn test_discriminant_implicit() {
+    let first: u8 = Implicit::First.discriminant();
+    assert_eq!(first, 0);
+    assert_eq!(Implicit::Second(5).discriminant(), 1);
+}
@@ -0,0 +1,232 @@
+use argcall::Callable;
+
+#[derive(Callable, Debug, PartialEq)]
+#[argcall(output = &'static str, dispatch)]
+enum Command {
+    #[argcall(fn = "foo called")]
+    Foo,
+    #[argcall(fn = "bar called")]
+    Bar,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, dispatch)]
+enum WithDrop {
+    #[argcall(fn = 1)]
+    One,
+}
+
+impl Drop for WithDrop {
+    fn drop(&mut self) {}
+}
+
+#[test]
+fn test_from_name() {
+    assert_eq!(Command::from_name("Foo").unwrap().call_fn(()), "foo called");
+    assert_eq!(Command::from_name("Bar"), Some(Command::Bar));
+    assert_eq!(Command::from_name("Baz"), None);
+    assert_eq!(Command::from_name("foo"), None);
+}
+
+#[test]
+fn test_all() {
+    assert_eq!(Command::all(), &[Command::Foo, Command::Bar]);
+    let outputs: Vec<_> = Command::all().iter().map(|c| c.call_fn(())).collect();
+    assert_eq!(outputs, vec!["foo called", "bar called"]);
+    assert_eq!(WithDrop::all().len(), 1);
+}
+
+#[test]
+fn test_try_from_str() {
+    let command = Command::try_from("Bar").unwrap();
+    assert_eq!(command.call_fn(()), "bar called");
+
+    let error = Command::try_from("Baz").unwrap_err();
+    assert_eq!(error.name(), "Baz");
+    assert_eq!(CommandNameError::expected(), ["Foo", "Bar"]);
+    assert_eq!(
+        error.to_string(),
+        "unknown variant `Baz`, expected one of `Foo`, `Bar`"
+    );
+    let error: Box<dyn std::error::Error> = Box::new(error);
+    assert!(error.to_string().contains("`Foo`, `Bar`"));
+}
+
+#[derive(Callable, Debug, PartialEq)]
+#[argcall(output = usize, dispatch)]
+enum Level<const N: usize> {
+    #[argcall(fn = N)]
+    Low,
+    #[argcall(fn = N * 2)]
+    High,
+}
+
+#[test]
+fn test_try_from_generic() {
+    let level = Level::<5>::try_from("High").unwrap();
+    assert_eq!(level, Level::High);
+    assert_eq!(level.call_fn(()), 10);
+    let error = Level::<5>::try_from("Mid").unwrap_err();
+    assert_eq!(error.name(), "Mid");
+    assert_eq!(LevelNameError::expected(), ["Low", "High"]);
+}
+
+#[derive(Callable, Debug, PartialEq)]
+#[argcall(output = i32, dispatch)]
+enum Cli {
+    #[argcall(fn = 1, rename = "list-all")]
+    ListAll,
+    #[argcall(rename = "rm", fn = 2)]
+    Remove,
+    #[argcall(fn = 3)]
+    Help,
+}
+
+#[test]
+fn test_rename() {
+    assert_eq!(Cli::from_name("list-all"), Some(Cli::ListAll));
+    assert_eq!(Cli::from_name("ListAll"), None);
+    assert_eq!(Cli::try_from("rm").unwrap().call_fn(()), 2);
+    assert!(Cli::try_from("Remove").is_err());
+    assert_eq!(Cli::from_name("Help"), Some(Cli::Help));
+    assert_eq!(CliNameError::expected(), ["list-all", "rm", "Help"]);
+}
+
+#[derive(Callable)]
+#[argcall(output = String, dispatch, call_all)]
+enum Report {
+    #[argcall(fn = "first".to_string())]
+    First,
+    #[argcall(fn = "second".to_string())]
+    Second,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (x: i32), dispatch, call_all)]
+enum Math {
+    #[argcall(fn = x + 1)]
+    Increment,
+    #[argcall(fn = x * x)]
+    Square,
+}
+
+#[test]
+fn test_call_all() {
+    assert_eq!(Report::call_all(), ["first", "second"]);
+    assert_eq!(Math::call_all((3,)), [4, 9]);
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (x: i32), dispatch, call_all, call_batch)]
+enum Small {
+    #[argcall(fn = x + 1)]
+    Increment,
+    #[argcall(fn = x * x)]
+    Square,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (x: i32), dispatch, call_all, call_batch)]
+enum Large {
+    #[argcall(fn = x)]
+    V0,
+    #[argcall(fn = x + 1)]
+    V1,
+    #[argcall(fn = x + 2)]
+    V2,
+    #[argcall(fn = x + 3)]
+    V3,
+    #[argcall(fn = x + 4)]
+    V4,
+    #[argcall(fn = x + 5)]
+    V5,
+    #[argcall(fn = x + 6)]
+    V6,
+    #[argcall(fn = x + 7)]
+    V7,
+    #[argcall(fn = x + 8)]
+    V8,
+    #[argcall(fn = x + 9)]
+    V9,
+}
+
+#[test]
+fn test_call_batch() {
+    let small = Small::call_batch((3,));
+    assert_eq!(small.as_slice(), Small::call_all((3,)));
+    assert!(!small.spilled());
+
+    let large = Large::call_batch((10,));
+    assert_eq!(large.as_slice(), Large::call_all((10,)));
+    assert_eq!(large.len(), 10);
+    assert!(large.spilled());
+}
+
+#[derive(Callable, Debug, PartialEq)]
+#[argcall(output = String, dispatch, call_all, call_batch)]
+enum Tool {
+    #[argcall(fn = "build".to_string())]
+    Build,
+    #[argcall(fn = "internal".to_string(), skip)]
+    Internal,
+    #[argcall(fn = format!("legacy {}", level), skip)]
+    Legacy { level: u8 },
+    #[argcall(fn = "test".to_string())]
+    Test,
+}
+
+#[test]
+fn test_skip() {
+    assert_eq!(Tool::from_name("Internal"), None);
+    assert_eq!(Tool::from_name("Legacy"), None);
+    assert!(Tool::try_from("Internal").is_err());
+    assert_eq!(Tool::all(), [Tool::Build, Tool::Test]);
+    assert_eq!(Tool::call_all(), ["build", "test"]);
+    assert_eq!(Tool::call_batch().as_slice(), ["build", "test"]);
+    assert_eq!(ToolNameError::expected(), ["Build", "Test"]);
+
+    assert_eq!(Tool::Internal.call_fn(()), "internal");
+    assert_eq!(ToolInternalCallable.call_fn(()), "internal");
+    assert_eq!(Tool::Legacy { level: 2 }.call_fn(()), "legacy 2");
+}
+
+#[test]
+fn test_name() {
+    assert_eq!(Cli::ListAll.name(), "list-all");
+    assert_eq!(Cli::Remove.name(), "rm");
+    assert_eq!(Cli::Help.name(), "Help");
+    for cli in Cli::all() {
+        assert_eq!(Cli::from_name(cli.name()).as_ref(), Some(cli));
+    }
+    assert_eq!(Tool::Internal.name(), "Internal");
+    assert_eq!(Tool::Legacy { level: 2 }.name(), "Legacy");
+}
+
+#[derive(Callable, Debug, PartialEq)]
+#[argcall(output = String, dispatch, bound = "T: Default + ToString")]
+enum Borrowed<'a, T> {
+    #[argcall(fn = T::default().to_string())]
+    Zero,
+    #[argcall(fn = text.to_string(), skip)]
+    Text {
+        text: &'a str,
+        marker: std::marker::PhantomData<T>,
+    },
+}
+
+#[test]
+fn test_try_from_with_lifetime() {
+    let input = String::from("Zero");
+    let parsed: Borrowed<'static, u8> = Borrowed::try_from(input.as_str()).unwrap();
+    assert_eq!(parsed, Borrowed::Zero);
+    assert_eq!(parsed.call_fn(()), "0");
+    let error = Borrowed::<u8>::try_from("Text").unwrap_err();
+    assert_eq!(error.name(), "Text");
+
+    let text = String::from("borrowed");
+    let value: Borrowed<'_, u8> = Borrowed::Text {
+        text: &text,
+        marker: std::marker::PhantomData,
+    };
+    assert_eq!(value.call_fn(()), "borrowed");
+}
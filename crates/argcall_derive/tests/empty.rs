@@ -0,0 +1,28 @@
+use argcall::{Callable, CallableMut, CallableOnce};
+
+#[allow(dead_code)]
+#[derive(Callable)]
+#[argcall(output = i32, args = (n: i32), allow_empty, dispatch)]
+enum Never {}
+
+#[allow(dead_code)]
+#[derive(CallableMut)]
+#[argcall(allow_empty)]
+enum NeverMut {}
+
+#[allow(dead_code)]
+#[derive(CallableOnce)]
+#[argcall(output = String, allow_empty)]
+enum NeverOnce {}
+
+fn assert_callable<C: Callable<(i32,), Output = i32>>() {}
+
+#[test]
+fn test_allow_empty() {
+    assert_callable::<Never>();
+    assert!(Never::all().is_empty());
+    assert_eq!(
+        Never::from_name("Any").map(|never| never.call_fn((1,))),
+        None
+    );
+}
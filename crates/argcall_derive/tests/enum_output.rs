@@ -0,0 +1,77 @@
+use argcall::{Callable, CallableMut};
+use std::iter::{Once, Rev};
+use std::ops::Range;
+use std::vec::IntoIter;
+
+#[derive(Callable)]
+#[argcall(output = IntoIter<i32>, args = (n: i32), fn = vec![n; 2].into_iter())]
+struct Twice;
+
+#[derive(Callable)]
+#[argcall(args = (n: i32), enum_output(Iterator, DoubleEndedIterator, ExactSizeIterator))]
+enum Numbers {
+    #[argcall(fn = 0..n, output = Range<i32>)]
+    Upto,
+    #[argcall(fn = (0..n).rev(), output = Rev<Range<i32>>)]
+    Down,
+    #[argcall(fn = std::iter::once(*value), output = Once<i32>)]
+    Single {
+        value: i32,
+    },
+    Repeated(Twice),
+    #[argcall(default, output = Range<i32>)]
+    Empty,
+}
+
+#[derive(CallableMut)]
+#[argcall(enum_output(Iterator))]
+enum Drain {
+    #[argcall(fn = std::mem::take(items).into_iter(), output = IntoIter<char>)]
+    Chars { items: Vec<char> },
+    #[argcall(fn = "ab".chars(), output = std::str::Chars<'static>)]
+    Fixed,
+}
+
+#[test]
+fn test_enum_output() {
+    assert_eq!(Numbers::Upto.call_fn((3,)).collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(Numbers::Down.call_fn((3,)).collect::<Vec<_>>(), [2, 1, 0]);
+    let single = Numbers::Single { value: 7 };
+    assert_eq!(single.call_fn((3,)).collect::<Vec<_>>(), [7]);
+    assert_eq!(
+        Numbers::Repeated(Twice).call_fn((5,)).collect::<Vec<_>>(),
+        [5, 5]
+    );
+    assert_eq!(Numbers::Empty.call_fn((3,)).count(), 0);
+    assert!(matches!(
+        Numbers::Upto.call_fn((1,)),
+        NumbersOutput::Upto(_)
+    ));
+
+    // The structs of unit variants return the output of the variant
+    let range: Range<i32> = NumbersUptoCallable.call_fn((2,));
+    assert_eq!(range, 0..2);
+}
+
+#[test]
+fn test_enum_output_traits() {
+    assert_eq!(
+        Numbers::Upto.call_fn((4,)).rev().collect::<Vec<_>>(),
+        [3, 2, 1, 0]
+    );
+    assert_eq!(Numbers::Down.call_fn((4,)).len(), 4);
+    assert_eq!(
+        Numbers::Repeated(Twice).call_fn((1,)).size_hint(),
+        (2, Some(2))
+    );
+}
+
+#[test]
+fn test_enum_output_mut() {
+    let mut drain = Drain::Chars {
+        items: vec!['x', 'y'],
+    };
+    assert_eq!(drain.call_fn_mut(()).collect::<String>(), "xy");
+    assert_eq!(drain.call_fn_mut(()).count(), 0);
+    assert_eq!(Drain::Fixed.call_fn_mut(()).collect::<String>(), "ab");
+}
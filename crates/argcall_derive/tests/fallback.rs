@@ -0,0 +1,70 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, fn = 0)]
+enum Score {
+    Missing,
+    Unknown,
+    #[argcall(fn = 10)]
+    Perfect,
+    #[argcall(fn = *points)]
+    Points {
+        points: i32,
+    },
+    #[argcall(default)]
+    Reset,
+}
+
+fn fallback() -> String {
+    "unhandled".to_string()
+}
+
+#[derive(Callable)]
+#[argcall(output = String, args = (name: &'static str), fn_path = "fallback")]
+enum Handler {
+    Ignored,
+    #[argcall(fn = format!("hello {}", name))]
+    Hello,
+}
+
+#[test]
+fn test_fallback_fn() {
+    assert_eq!(Score::Missing.call_fn(()), 0);
+    assert_eq!(Score::Unknown.call_fn(()), 0);
+    assert_eq!(Score::Perfect.call_fn(()), 10);
+    assert_eq!(Score::Points { points: 3 }.call_fn(()), 3);
+    assert_eq!(Score::Reset.call_fn(()), 0);
+    assert_eq!(ScoreMissingCallable.call_fn(()), 0);
+}
+
+#[test]
+fn test_fallback_fn_path() {
+    assert_eq!(Handler::Ignored.call_fn(("argcall",)), "unhandled");
+    assert_eq!(Handler::Hello.call_fn(("argcall",)), "hello argcall");
+    assert_eq!(HandlerIgnoredCallable.call_fn(("argcall",)), "unhandled");
+}
+
+fn common() -> &'static str {
+    "common"
+}
+
+#[derive(Callable)]
+#[argcall(output = &'static str, fn = common())]
+enum Group {
+    First,
+    Second,
+    #[argcall(fn = "own")]
+    Own,
+    #[argcall(fn_path = "common")]
+    Explicit,
+}
+
+#[test]
+fn test_variant_fn_takes_precedence() {
+    assert_eq!(Group::First.call_fn(()), "common");
+    assert_eq!(Group::Second.call_fn(()), "common");
+    assert_eq!(Group::Own.call_fn(()), "own");
+    assert_eq!(Group::Explicit.call_fn(()), "common");
+    assert_eq!(GroupSecondCallable.call_fn(()), "common");
+    assert_eq!(GroupOwnCallable.call_fn(()), "own");
+}
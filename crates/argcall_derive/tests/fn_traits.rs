@@ -0,0 +1,61 @@
+//! Requires a nightly toolchain: `RUSTFLAGS="--cfg argcall_nightly" cargo +nightly test --test fn_traits`
+#![cfg(argcall_nightly)]
+#![feature(fn_traits, unboxed_closures)]
+
+use argcall::{Callable, CallableMut, CallableOnce};
+
+#[derive(Callable)]
+#[argcall(output = i32, fn_traits)]
+enum Value {
+    #[argcall(fn = 1)]
+    One,
+    #[argcall(fn = *x * 2)]
+    Double { x: i32 },
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (a: i32, b: i32), fn = a + b + self.0, fn_traits)]
+struct Add(i32);
+
+#[derive(CallableMut)]
+#[argcall(output = u32, fn = { *count += 1; *count }, fn_traits)]
+struct Counter {
+    count: u32,
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = String, fn = _0, fn_traits)]
+struct Take(String);
+
+#[derive(Callable)]
+#[argcall(output = i32, fn = 7)]
+struct Plain;
+
+fn call_twice(f: impl Fn() -> i32) -> i32 {
+    f() + f()
+}
+
+#[test]
+fn test_call_syntax() {
+    assert_eq!(Value::One(), 1);
+    assert_eq!(Value::Double { x: 4 }(), 8);
+    assert_eq!(ValueOneCallable(), 1);
+    assert_eq!(Add(1)(2, 3), 6);
+    assert_eq!(call_twice(Value::One), 2);
+}
+
+#[test]
+fn test_callable_from_fn() {
+    assert_eq!(Value::One.call_fn(()), 1);
+    assert_eq!(Add(1).call_fn((2, 3)), 6);
+    assert_eq!(Plain.call_fn(()), 7);
+}
+
+#[test]
+fn test_call_mut_and_once() {
+    let mut counter = Counter { count: 0 };
+    assert_eq!(counter(), 1);
+    assert_eq!(counter.call_fn_mut(()), 2);
+    assert_eq!(Take("moved".to_string())(), "moved");
+    assert_eq!(Take("once".to_string()).call_fn_once(()), "once");
+}
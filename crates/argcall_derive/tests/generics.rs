@@ -0,0 +1,103 @@
+use argcall::{Callable, CallableOnce};
+use core::marker::PhantomData;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum Wrap<T: Callable<Output = i32>> {
+    Inner(T),
+    #[argcall(fn = 0)]
+    Empty,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum E<'a, T>
+where
+    &'a T: Callable<Output = i32>,
+{
+    Ref(&'a T),
+}
+
+#[derive(Callable)]
+#[argcall(output = usize)]
+enum Fixed<const N: usize> {
+    #[argcall(fn = N)]
+    Len,
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = T, fn = value)]
+struct Holder<T> {
+    value: T,
+}
+
+struct Two;
+
+impl Callable for Two {
+    type Output = i32;
+
+    fn call_fn(&self, _: ()) -> Self::Output {
+        2
+    }
+}
+
+impl Callable for &Two {
+    type Output = i32;
+
+    fn call_fn(&self, _: ()) -> Self::Output {
+        (**self).call_fn(())
+    }
+}
+
+#[test]
+fn test_generic_enum() {
+    assert_eq!(Wrap::Inner(Two).call_fn(()), 2);
+    assert_eq!(Wrap::<Two>::Empty.call_fn(()), 0);
+    assert_eq!(WrapEmptyCallable::<Two>(PhantomData).call_fn(()), 0);
+    let wrap: Wrap<Two> = WrapEmptyCallable(PhantomData).into();
+    assert_eq!(wrap.call_fn(()), 0);
+}
+
+#[test]
+fn test_lifetime_enum() {
+    let two = Two;
+    assert_eq!(E::Ref(&two).call_fn(()), 2);
+}
+
+#[test]
+fn test_const_generic_enum() {
+    assert_eq!(Fixed::<3>::Len.call_fn(()), 3);
+    assert_eq!(FixedLenCallable::<4>.call_fn(()), 4);
+}
+
+#[test]
+fn test_generic_struct() {
+    let holder = Holder {
+        value: "owned".to_string(),
+    };
+    assert_eq!(holder.call_fn_once(()), "owned");
+}
+
+mod local_core {
+    use argcall::Callable;
+
+    // Shadows the `core` crate for relative paths in this module
+    #[allow(dead_code)]
+    mod core {}
+
+    #[derive(Callable)]
+    #[argcall(output = usize)]
+    enum Size<T> {
+        #[argcall(fn = ::core::mem::size_of::<T>())]
+        Of,
+        #[argcall(fn = 0)]
+        Value { value: T },
+    }
+
+    #[test]
+    fn test_local_core_module() {
+        assert_eq!(Size::<u32>::Of.call_fn(()), 4);
+        assert_eq!(Size::Value { value: 1u8 }.call_fn(()), 0);
+        assert_eq!(SizeOfCallable::<u64>(Default::default()).call_fn(()), 8);
+    }
+}
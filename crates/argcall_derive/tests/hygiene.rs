@@ -0,0 +1,82 @@
+use argcall::Callable;
+
+fn call_fn() -> i32 {
+    100
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (n: i32))]
+enum Collide {
+    #[argcall(fn = *call_fn + *args + n)]
+    Named { call_fn: i32, args: i32 },
+    #[argcall(fn = *value + *output + n)]
+    Internal { value: i32, output: i32 },
+    #[argcall(fn = *r#type * n)]
+    Raw { r#type: i32 },
+    #[argcall(fn = crate::call_fn() + n)]
+    Shadowed,
+}
+
+#[derive(Callable)]
+#[argcall(output = Box<String>, args = (suffix: &str), box_output)]
+enum Boxed {
+    #[argcall(fn = format!("{}{}", output, suffix))]
+    Output { output: String },
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (x: i32))]
+enum Shadow {
+    #[argcall(fn = x)]
+    Named { x: i32 },
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (n: i32), fn = *args * n + *call_fn)]
+struct Fields {
+    args: i32,
+    call_fn: i32,
+}
+
+#[test]
+fn test_fields_named_like_generated_idents() {
+    assert_eq!(
+        Collide::Named {
+            call_fn: 1,
+            args: 2
+        }
+        .call_fn((3,)),
+        6
+    );
+    assert_eq!(
+        Collide::Internal {
+            value: 1,
+            output: 2
+        }
+        .call_fn((3,)),
+        6
+    );
+    assert_eq!(Collide::Raw { r#type: 2 }.call_fn((3,)), 6);
+    assert_eq!(Collide::Shadowed.call_fn((3,)), 103);
+    assert_eq!(CollideShadowedCallable.call_fn((3,)), 103);
+    assert_eq!(
+        *Boxed::Output {
+            output: "a".to_string()
+        }
+        .call_fn(("b",)),
+        "ab"
+    );
+    assert_eq!(
+        Fields {
+            args: 2,
+            call_fn: 1
+        }
+        .call_fn((3,)),
+        7
+    );
+}
+
+#[test]
+fn test_args_shadow_fields() {
+    assert_eq!(Shadow::Named { x: 1 }.call_fn((2,)), 2);
+}
@@ -0,0 +1,55 @@
+use argcall::{Callable, CallableMut};
+
+#[derive(CallableMut)]
+#[argcall(output = u32, args = (by: u32))]
+enum Counter {
+    #[argcall(fn_path = "Self::step", arg_order = [self])]
+    Steps { count: u32 },
+    #[argcall(fn_path = "Self::step", arg_order = [self])]
+    Idle,
+}
+
+impl Counter {
+    fn step(&mut self) -> u32 {
+        match self {
+            Counter::Steps { count } => {
+                *count += 1;
+                *count
+            }
+            Counter::Idle => 0,
+        }
+    }
+}
+
+#[derive(Callable)]
+#[argcall(output = String)]
+enum Labeled {
+    #[argcall(fn_path = "Self::describe", arg_order = [self, label])]
+    Named { label: String },
+}
+
+impl Labeled {
+    fn describe(&self, label: &str) -> String {
+        match self {
+            Labeled::Named { .. } => format!("named {}", label),
+        }
+    }
+}
+
+#[test]
+fn test_mut_receiver() {
+    let mut counter = Counter::Steps { count: 0 };
+    assert_eq!(counter.call_fn_mut((1,)), 1);
+    assert_eq!(counter.call_fn_mut((1,)), 2);
+    assert!(matches!(counter, Counter::Steps { count: 2 }));
+    assert_eq!(Counter::Idle.call_fn_mut((1,)), 0);
+    assert_eq!(CounterIdleCallable.call_fn_mut((1,)), 0);
+}
+
+#[test]
+fn test_receiver_with_fields() {
+    let labeled = Labeled::Named {
+        label: "x".to_string(),
+    };
+    assert_eq!(labeled.call_fn(()), "named x");
+}
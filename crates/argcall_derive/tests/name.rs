@@ -0,0 +1,36 @@
+use argcall::{Callable, CallableMut};
+
+#[derive(Callable)]
+#[argcall(output = i32, name)]
+enum Shape {
+    #[argcall(fn = 0)]
+    Point,
+    #[argcall(fn = *r * 3, rename = "round")]
+    Circle { r: i32 },
+    #[argcall(fn = _0 * _1)]
+    Rect(i32, i32),
+    #[argcall(fn = *_0)]
+    r#Box(i32),
+}
+
+#[derive(CallableMut)]
+#[argcall(output = T, name)]
+enum Counter<T: Copy> {
+    #[argcall(fn = *value)]
+    Fixed { value: T },
+}
+
+#[test]
+fn test_name() {
+    assert_eq!(Shape::Point.name(), "Point");
+    assert_eq!(Shape::Circle { r: 2 }.name(), "round");
+    assert_eq!(Shape::Rect(2, 3).name(), "Rect");
+    assert_eq!(Shape::Box(1).name(), "Box");
+    assert_eq!(Counter::Fixed { value: 1u8 }.name(), "Fixed");
+}
+
+#[test]
+fn test_name_is_const() {
+    const NAME: &str = Shape::Rect(1, 1).name();
+    assert_eq!(NAME, "Rect");
+}
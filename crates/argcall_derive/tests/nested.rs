@@ -0,0 +1,60 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = String, args = (name: &'static str))]
+enum Leaf {
+    #[argcall(fn = format!("hello {}", name))]
+    Hello,
+    #[argcall(fn = format!("bye {}", name))]
+    Bye,
+}
+
+#[derive(Callable)]
+#[argcall(args = (name: &'static str))]
+enum Middle {
+    Leaf(Leaf),
+    #[argcall(fn = name.to_uppercase())]
+    Shout,
+}
+
+#[derive(Callable)]
+#[argcall(args = (name: &'static str))]
+enum Outer {
+    Middle(Middle),
+    Leaf(Leaf),
+}
+
+#[derive(Callable)]
+#[argcall(output = i64)]
+enum Expr {
+    #[argcall(fn = *_0)]
+    Lit(i64),
+    #[argcall(fn = -_0.call_fn(()))]
+    Neg(Box<Expr>),
+    #[argcall(fn = _0.call_fn(()) + _1.call_fn(()))]
+    Add(Box<Expr>, Box<Expr>),
+}
+
+#[test]
+fn test_two_levels_of_delegation() {
+    let call = |outer: Outer| outer.call_fn(("argcall",));
+    assert_eq!(
+        call(Outer::Middle(Middle::Leaf(Leaf::Hello))),
+        "hello argcall"
+    );
+    assert_eq!(call(Outer::Middle(Middle::Leaf(Leaf::Bye))), "bye argcall");
+    assert_eq!(call(Outer::Middle(Middle::Shout)), "ARGCALL");
+    assert_eq!(call(Outer::Leaf(Leaf::Bye)), "bye argcall");
+}
+
+#[test]
+fn test_recursive_enum() {
+    let expr = Expr::Add(
+        Box::new(Expr::Lit(2)),
+        Box::new(Expr::Neg(Box::new(Expr::Add(
+            Box::new(Expr::Lit(3)),
+            Box::new(Expr::Lit(4)),
+        )))),
+    );
+    assert_eq!(expr.call_fn(()), -5);
+}
@@ -0,0 +1,23 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, no_structs)]
+enum Op {
+    #[argcall(fn = 1)]
+    One,
+    #[argcall(fn = 2)]
+    Two,
+    #[argcall(fn = *x)]
+    Value { x: i32 },
+}
+
+// Would clash with the struct generated for `Op::One` if it were generated
+#[allow(dead_code)]
+struct OpOneCallable;
+
+#[test]
+fn test_no_structs() {
+    assert_eq!(Op::One.call_fn(()), 1);
+    assert_eq!(Op::Two.call_fn(()), 2);
+    assert_eq!(Op::Value { x: 3 }.call_fn(()), 3);
+}
@@ -0,0 +1,35 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = u8)]
+#[non_exhaustive]
+enum Level {
+    #[argcall(fn = 1)]
+    Low,
+    #[argcall(fn = *value)]
+    #[non_exhaustive]
+    Custom { value: u8 },
+    #[argcall(fn = *_0)]
+    #[non_exhaustive]
+    Raw(u8),
+}
+
+#[derive(Callable)]
+#[argcall(output = u8, fn = *value)]
+#[non_exhaustive]
+struct Setting {
+    value: u8,
+}
+
+#[test]
+fn test_non_exhaustive_enum() {
+    assert_eq!(Level::Low.call_fn(()), 1);
+    assert_eq!(Level::Custom { value: 2 }.call_fn(()), 2);
+    assert_eq!(Level::Raw(3).call_fn(()), 3);
+    assert_eq!(LevelLowCallable.call_fn(()), 1);
+}
+
+#[test]
+fn test_non_exhaustive_struct() {
+    assert_eq!(Setting { value: 4 }.call_fn(()), 4);
+}
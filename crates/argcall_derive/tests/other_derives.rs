@@ -0,0 +1,28 @@
+use argcall::Callable;
+
+/// Fields and variants carry attributes owned by another derive, as with `clap::Subcommand`
+#[derive(Debug, thiserror::Error, Callable)]
+#[argcall(output = String)]
+enum Failure {
+    #[error("failed to read {path}")]
+    #[argcall(fn = format!("read {}", path))]
+    Read {
+        path: String,
+        #[source]
+        source: std::fmt::Error,
+    },
+    #[error("timed out")]
+    #[argcall(fn = "timeout".to_string())]
+    Timeout,
+}
+
+#[test]
+fn test_foreign_attributes_are_ignored() {
+    let read = Failure::Read {
+        path: "a.txt".to_string(),
+        source: std::fmt::Error,
+    };
+    assert_eq!(read.to_string(), "failed to read a.txt");
+    assert_eq!(read.call_fn(()), "read a.txt");
+    assert_eq!(Failure::Timeout.call_fn(()), "timeout");
+}
@@ -0,0 +1,125 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = u32)]
+enum Value {
+    #[argcall(output = u8, fn = small())]
+    Small,
+    #[argcall(fn = large())]
+    Large,
+    #[argcall(output = u8, fn = *x)]
+    Named { x: u8 },
+    #[argcall(output = u8)]
+    Inner(SmallValue),
+}
+
+struct SmallValue;
+
+impl Callable for SmallValue {
+    type Output = u8;
+
+    fn call_fn(&self, _: ()) -> Self::Output {
+        7
+    }
+}
+
+fn small() -> u8 {
+    255
+}
+
+fn large() -> u32 {
+    1 << 20
+}
+
+#[test]
+fn test_variant_output_is_converted() {
+    assert_eq!(Value::Small.call_fn(()), 255u32);
+    assert_eq!(Value::Large.call_fn(()), 1 << 20);
+    assert_eq!(Value::Named { x: 3 }.call_fn(()), 3u32);
+    assert_eq!(Value::Inner(SmallValue).call_fn(()), 7u32);
+}
+
+#[test]
+fn test_variant_struct_uses_variant_output() {
+    let small: u8 = ValueSmallCallable.call_fn(());
+    assert_eq!(small, 255);
+    let large: u32 = ValueLargeCallable.call_fn(());
+    assert_eq!(large, 1 << 20);
+}
+
+struct Seven;
+
+impl Callable for Seven {
+    type Output = u32;
+
+    fn call_fn(&self, _: ()) -> Self::Output {
+        7
+    }
+}
+
+#[derive(Callable)]
+enum Inferred {
+    First(SmallValue),
+    Second(SmallValue),
+}
+
+#[derive(Callable)]
+enum InferredMixed {
+    #[argcall(fn = 1)]
+    Unit,
+    Seven(Seven),
+    #[argcall(fn = *x * 2)]
+    Named {
+        x: u32,
+    },
+}
+
+#[derive(Callable)]
+#[argcall(args = (n: i32))]
+enum InferredWithArgs<F: Callable<(i32,)>> {
+    Inner(F),
+}
+
+#[test]
+fn test_inferred_output() {
+    let value: u8 = Inferred::First(SmallValue).call_fn(());
+    assert_eq!(value, 7);
+    assert_eq!(Inferred::Second(SmallValue).call_fn(()), 7);
+
+    let value: u32 = InferredMixed::Seven(Seven).call_fn(());
+    assert_eq!(value, 7);
+    assert_eq!(InferredMixed::Unit.call_fn(()), 1);
+    assert_eq!(InferredMixed::Named { x: 4 }.call_fn(()), 8);
+
+    let double = InferredWithArgs::Inner(|n: i32| n * 2);
+    assert_eq!(double.call_fn((21,)), 42);
+}
+
+#[derive(Callable)]
+#[argcall(output = Vec<i32>)]
+enum Typed {
+    #[argcall(fn = vec![1, 2])]
+    Pair,
+}
+
+#[derive(Callable)]
+#[argcall(output = "Vec<i32>")]
+enum Quoted {
+    #[argcall(fn = vec![1, 2])]
+    Pair,
+    #[argcall(output = "[i32; 1]", fn = [3])]
+    Array,
+}
+
+#[derive(Callable)]
+#[argcall(output = "Option<&'static str>", fn = Some("struct"))]
+struct QuotedStruct;
+
+#[test]
+fn test_output_as_string() {
+    assert_eq!(Typed::Pair.call_fn(()), Quoted::Pair.call_fn(()));
+    assert_eq!(Quoted::Array.call_fn(()), [3]);
+    let array: [i32; 1] = QuotedArrayCallable.call_fn(());
+    assert_eq!(array, [3]);
+    assert_eq!(QuotedStruct.call_fn(()), Some("struct"));
+}
@@ -0,0 +1,149 @@
+use argcall::Callable;
+use core::marker::PhantomData;
+
+mod helpers {
+    pub fn compute() -> i32 {
+        42
+    }
+
+    pub fn scale(x: &i32, factor: &i32) -> i32 {
+        x * factor
+    }
+}
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    #[argcall(fn_path = "crate::helpers::compute")]
+    Crate,
+    #[argcall(fn_path = "helpers::compute")]
+    Relative,
+    #[argcall(fn_path = "self::helpers::scale")]
+    Named { x: i32, factor: i32 },
+}
+
+#[test]
+fn test_multi_segment_fn_path() {
+    assert_eq!(MyEnum::Crate.call_fn(()), 42);
+    assert_eq!(MyEnum::Relative.call_fn(()), 42);
+    assert_eq!(MyEnumCrateCallable.call_fn(()), 42);
+    assert_eq!(MyEnum::Named { x: 2, factor: 3 }.call_fn(()), 6);
+}
+
+fn make(name: &str, count: &usize) -> String {
+    name.repeat(*count)
+}
+
+#[derive(Callable)]
+#[argcall(output = String)]
+enum Reordered {
+    #[argcall(fn_path = "make", arg_order = [name, count])]
+    Named { count: usize, name: String },
+    #[argcall(fn_path = "make", arg_order = [_1, _0])]
+    Tuple(usize, String),
+    #[argcall(arg_order = [name], fn_path = "str::to_uppercase")]
+    Subset { name: String, ignored: u8 },
+}
+
+#[derive(Callable)]
+#[argcall(output = String, fn_path = "make", arg_order = [name, count])]
+struct ReorderedStruct {
+    count: usize,
+    name: String,
+}
+
+#[test]
+fn test_arg_order() {
+    let named = Reordered::Named {
+        count: 2,
+        name: "ab".to_string(),
+    };
+    assert_eq!(named.call_fn(()), "abab");
+    assert_eq!(Reordered::Tuple(3, "x".to_string()).call_fn(()), "xxx");
+    let subset = Reordered::Subset {
+        name: "hi".to_string(),
+        ignored: 0,
+    };
+    assert_eq!(subset.call_fn(()), "HI");
+    let reordered = ReorderedStruct {
+        count: 2,
+        name: "c".to_string(),
+    };
+    assert_eq!(reordered.call_fn(()), "cc");
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, args = (n: i32))]
+enum Handled<T: Default> {
+    #[argcall(fn_path = "Self::handle")]
+    Unit,
+    #[argcall(fn = Self::scale(n, *x))]
+    Named { x: i32 },
+    #[argcall(fn = Self::default_len(n))]
+    Generic,
+    #[allow(dead_code)]
+    #[argcall(fn = 0)]
+    Value(T),
+}
+
+impl<T: Default> Handled<T> {
+    fn handle() -> i32 {
+        7
+    }
+
+    fn scale(n: i32, x: i32) -> i32 {
+        n * x
+    }
+
+    fn default_len(n: i32) -> i32 {
+        let _ = T::default();
+        n + 1
+    }
+}
+
+#[test]
+fn test_self_fn_path() {
+    assert_eq!(Handled::<u8>::Unit.call_fn((0,)), 7);
+    assert_eq!(Handled::<u8>::Named { x: 3 }.call_fn((2,)), 6);
+    assert_eq!(Handled::<u8>::Generic.call_fn((2,)), 3);
+    assert_eq!(HandledUnitCallable::<u8>(PhantomData).call_fn((0,)), 7);
+    assert_eq!(HandledGenericCallable::<u8>(PhantomData).call_fn((2,)), 3);
+}
+
+mod convert {
+    pub fn parse<T: std::str::FromStr + Default>(text: &str) -> T {
+        text.parse().unwrap_or_default()
+    }
+
+    pub fn zero<T: Default>() -> T {
+        T::default()
+    }
+
+    pub fn widen<T: Copy + Into<u64>>(value: &T) -> u64 {
+        (*value).into()
+    }
+}
+
+#[derive(Callable)]
+#[argcall(output = u64)]
+enum Parsed {
+    #[argcall(fn_path = "convert::parse::<u64>")]
+    Text(&'static str),
+    #[argcall(fn_path = "convert::zero::<u64>")]
+    Zero,
+    #[argcall(fn_path = "convert::widen::<u32>")]
+    Small(u32),
+}
+
+#[derive(Callable)]
+#[argcall(output = Vec<u8>, fn_path = "Vec::<u8>::new")]
+struct Buffer;
+
+#[test]
+fn test_turbofish_fn_path() {
+    assert_eq!(Parsed::Text("42").call_fn(()), 42);
+    assert_eq!(Parsed::Zero.call_fn(()), 0);
+    assert_eq!(ParsedZeroCallable.call_fn(()), 0);
+    assert_eq!(Parsed::Small(7).call_fn(()), 7);
+    assert!(Buffer.call_fn(()).is_empty());
+}
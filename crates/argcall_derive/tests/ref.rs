@@ -0,0 +1,122 @@
+use argcall::{Callable, CallableRef};
+use std::borrow::Cow;
+
+#[derive(Callable)]
+#[argcall(ref, output = &'a str)]
+enum Field {
+    #[argcall(fn = "none")]
+    Empty,
+    #[argcall(fn = label.as_str())]
+    Named {
+        label: String,
+    },
+    #[argcall(fn = _0.as_str())]
+    Tuple(String),
+    Inner(Label),
+}
+
+#[derive(Callable)]
+#[argcall(ref = 'x, output = &'x [T], fn = items.as_slice())]
+struct Items<T> {
+    items: Vec<T>,
+}
+
+#[derive(Callable)]
+#[argcall(ref, output = &'a T, args = (index: usize), fn = &self.0[index])]
+struct Nth<'a, T>(&'a [T]);
+
+#[derive(Callable)]
+#[argcall(ref = 'x, output = &'x str, fn = items)]
+struct Items2 {
+    items: String,
+}
+
+struct Label(String);
+
+impl<'a> CallableRef<'a> for Label {
+    type Output = &'a str;
+    fn call_fn_ref(&'a self, _: ()) -> Self::Output {
+        &self.0
+    }
+}
+
+#[test]
+fn test_borrowed_output() {
+    let named = Field::Named {
+        label: "named".to_string(),
+    };
+    assert_eq!(named.call_fn_ref(()), "named");
+    assert_eq!(Field::Tuple("tuple".to_string()).call_fn_ref(()), "tuple");
+    assert_eq!(
+        Field::Inner(Label("inner".to_string())).call_fn_ref(()),
+        "inner"
+    );
+    assert_eq!(Field::Empty.call_fn_ref(()), "none");
+    assert_eq!(FieldEmptyCallable.call_fn_ref(()), "none");
+}
+
+#[test]
+fn test_borrowed_struct() {
+    let items = Items { items: vec![1, 2] };
+    assert_eq!(items.call_fn_ref(()), &[1, 2]);
+
+    let values = [3, 4];
+    assert_eq!(*Nth(&values).call_fn_ref((1,)), 4);
+    assert_eq!(Items2 { items: "s".into() }.call_fn_ref(()), "s");
+}
+
+#[derive(Callable)]
+#[argcall(ref, output = Cow<'a, str>, args = (suffix: &str))]
+enum Text {
+    #[argcall(fn = Cow::Borrowed(text.as_str()))]
+    Borrowed { text: String },
+    #[argcall(fn = Cow::Owned(format!("{}{}", _0, suffix)))]
+    Suffixed(String),
+    #[argcall(fn = Cow::Borrowed("static"))]
+    Static,
+    #[argcall(fn = if _0.is_empty() { Cow::Owned("empty".to_string()) } else { Cow::Borrowed(_0.as_str()) })]
+    Either(String),
+}
+
+#[derive(Callable)]
+#[argcall(ref, output = Cow<'a, [u8]>, fn = match trim { true => Cow::Owned(bytes.trim_ascii().to_vec()), false => Cow::Borrowed(bytes) })]
+struct Bytes {
+    bytes: Vec<u8>,
+    trim: bool,
+}
+
+#[test]
+fn test_cow_output() {
+    let borrowed = Text::Borrowed {
+        text: "text".to_string(),
+    };
+    let output = borrowed.call_fn_ref(("!",));
+    assert!(matches!(output, Cow::Borrowed("text")));
+    let suffixed = Text::Suffixed("text".to_string());
+    assert!(matches!(suffixed.call_fn_ref(("!",)), Cow::Owned(text) if text == "text!"));
+    assert!(matches!(
+        Text::Static.call_fn_ref(("!",)),
+        Cow::Borrowed("static")
+    ));
+    assert!(matches!(
+        TextStaticCallable.call_fn_ref(("!",)),
+        Cow::Borrowed("static")
+    ));
+    assert!(matches!(
+        Text::Either(String::new()).call_fn_ref(("",)),
+        Cow::Owned(_)
+    ));
+    let either = Text::Either("some".to_string());
+    assert!(matches!(either.call_fn_ref(("",)), Cow::Borrowed("some")));
+
+    let bytes = Bytes {
+        bytes: b" a ".to_vec(),
+        trim: false,
+    };
+    assert!(matches!(bytes.call_fn_ref(()), Cow::Borrowed(b" a ")));
+    let trimmed = Bytes {
+        trim: true,
+        ..bytes
+    };
+    assert_eq!(trimmed.call_fn_ref(()), &b"a"[..]);
+}
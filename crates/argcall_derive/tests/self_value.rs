@@ -0,0 +1,75 @@
+use argcall::{Callable, CallableMut, CallableOnce};
+
+#[derive(Callable, Debug)]
+#[argcall(output = String)]
+enum Shape {
+    #[argcall(fn = self.describe())]
+    Unit,
+    #[argcall(fn = format!("{} of area {}", self.describe(), w * h))]
+    Rect { w: u32, h: u32 },
+    #[argcall(fn = format!("{} with radius {}", self.describe(), _0))]
+    Circle(u32),
+    #[argcall(fn = self::label(self))]
+    Path,
+}
+
+impl Shape {
+    fn describe(&self) -> String {
+        format!("{:?}", self).to_lowercase()
+    }
+}
+
+fn label(shape: &Shape) -> String {
+    format!("<{}>", shape.describe())
+}
+
+#[derive(CallableMut)]
+#[argcall(output = usize)]
+enum Tally {
+    #[argcall(fn = self.bump())]
+    Start,
+}
+
+impl Tally {
+    fn bump(&mut self) -> usize {
+        1
+    }
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = String)]
+enum Owned {
+    #[argcall(fn = self.into_name())]
+    Name,
+}
+
+impl Owned {
+    fn into_name(self) -> String {
+        "owned".to_string()
+    }
+}
+
+#[test]
+fn test_self_in_variants() {
+    assert_eq!(Shape::Unit.call_fn(()), "unit");
+    assert_eq!(
+        Shape::Rect { w: 2, h: 3 }.call_fn(()),
+        "rect { w: 2, h: 3 } of area 6"
+    );
+    assert_eq!(Shape::Circle(4).call_fn(()), "circle(4) with radius 4");
+    assert_eq!(Shape::Path.call_fn(()), "<path>");
+}
+
+#[test]
+fn test_self_in_variant_structs() {
+    assert_eq!(ShapeUnitCallable.call_fn(()), "unit");
+    assert_eq!(ShapePathCallable.call_fn(()), "<path>");
+    assert_eq!(TallyStartCallable.call_fn_mut(()), 1);
+    assert_eq!(OwnedNameCallable.call_fn_once(()), "owned");
+}
+
+#[test]
+fn test_self_by_receiver() {
+    assert_eq!(Tally::Start.call_fn_mut(()), 1);
+    assert_eq!(Owned::Name.call_fn_once(()), "owned");
+}
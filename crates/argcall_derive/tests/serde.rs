@@ -0,0 +1,42 @@
+use argcall::Callable;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Callable)]
+#[serde(tag = "op", rename_all = "lowercase")]
+#[argcall(output = i32)]
+enum Op {
+    #[argcall(fn = value * 2)]
+    Double { value: i32 },
+    #[argcall(fn = scale(*value, *factor))]
+    Scale { value: i32, factor: i32 },
+    #[argcall(fn = 0)]
+    Zero,
+}
+
+fn scale(value: i32, factor: i32) -> i32 {
+    value * factor
+}
+
+#[test]
+fn test_call_from_value() {
+    let value = serde_json::json!({"op": "double", "value": 3});
+    assert_eq!(argcall::call_from_value::<Op>(value).unwrap(), 6);
+    let value = serde_json::json!({"op": "zero"});
+    assert_eq!(argcall::call_from_value::<Op>(value).unwrap(), 0);
+}
+
+#[test]
+fn test_round_trip() {
+    let op = Op::Scale {
+        value: 3,
+        factor: 4,
+    };
+    let json = serde_json::to_value(&op).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"op": "scale", "value": 3, "factor": 4})
+    );
+    let decoded: Op = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded, op);
+    assert_eq!(decoded.call_fn(()), 12);
+}
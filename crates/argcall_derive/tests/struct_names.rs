@@ -0,0 +1,114 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum Default {
+    #[argcall(fn = 1)]
+    One,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, struct_suffix = "Action")]
+enum Suffixed {
+    #[argcall(fn = 1)]
+    One,
+    #[argcall(fn = 2, struct_name = "TwoAction")]
+    Two,
+}
+
+#[test]
+fn test_default_name() {
+    assert_eq!(DefaultOneCallable.call_fn(()), 1);
+    assert_eq!(Default::One.call_fn(()), 1);
+}
+
+#[test]
+fn test_renamed() {
+    assert_eq!(SuffixedOneAction.call_fn(()), 1);
+    assert_eq!(Suffixed::One.call_fn(()), 1);
+    assert_eq!(TwoAction.call_fn(()), 2);
+    assert_eq!(Suffixed::Two.call_fn(()), 2);
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, struct_derive(Clone, Copy, Debug, PartialEq))]
+enum Derived {
+    #[argcall(fn = 1)]
+    One,
+}
+
+struct NotDebug;
+
+#[derive(Callable)]
+#[argcall(output = usize)]
+enum SizeOf<T> {
+    #[argcall(fn = core::mem::size_of::<T>())]
+    Size,
+    #[allow(dead_code)]
+    #[argcall(fn = 0)]
+    Value(T),
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum snake_case {
+    #[argcall(fn = 1)]
+    one,
+}
+
+#[test]
+fn test_derived_traits() {
+    let one = DerivedOneCallable;
+    let copied = one;
+    assert_eq!(one, copied.clone());
+    assert_eq!(format!("{:?}", one), "DerivedOneCallable");
+    assert_eq!(copied.call_fn(()), 1);
+    assert_eq!(Derived::One.call_fn(()), 1);
+}
+
+#[test]
+fn test_no_derived_traits() {
+    let size = SizeOfSizeCallable::<NotDebug>(core::marker::PhantomData);
+    assert_eq!(size.call_fn(()), 0);
+    assert_eq!(SizeOf::<NotDebug>::Size.call_fn(()), 0);
+    assert_eq!(snake_caseoneCallable.call_fn(()), 1);
+    assert_eq!(snake_case::one.call_fn(()), 1);
+}
+
+#[derive(Callable)]
+#[argcall(output = &'static str, struct_derive(PartialEq, Eq, Hash))]
+enum Key {
+    #[argcall(fn = "up")]
+    Up,
+    #[argcall(fn = "down")]
+    Down,
+}
+
+#[test]
+fn test_structs_as_keys() {
+    use std::collections::{HashMap, HashSet};
+
+    let mut seen = HashSet::new();
+    assert!(seen.insert(KeyUpCallable));
+    assert!(!seen.insert(KeyUpCallable));
+    assert_eq!(seen.len(), 1);
+
+    let mut presses = HashMap::new();
+    *presses.entry(KeyUpCallable).or_insert(0) += 1;
+    *presses.entry(KeyUpCallable).or_insert(0) += 1;
+    assert_eq!(presses[&KeyUpCallable], 2);
+    assert!(KeyDownCallable == KeyDownCallable);
+    assert_eq!(Key::Up.call_fn(()), KeyUpCallable.call_fn(()));
+    assert_eq!(Key::Down.call_fn(()), "down");
+}
+
+#[test]
+fn test_struct_into_enum() {
+    let one: Default = DefaultOneCallable.into();
+    assert_eq!(one.call_fn(()), 1);
+    let variants: Vec<Suffixed> = vec![SuffixedOneAction.into(), TwoAction.into()];
+    let outputs: Vec<i32> = variants.iter().map(|variant| variant.call_fn(())).collect();
+    assert_eq!(outputs, [1, 2]);
+    assert!(matches!(Suffixed::from(TwoAction), Suffixed::Two));
+}
@@ -0,0 +1,50 @@
+use argcall::Callable;
+
+mod commands {
+    use argcall::Callable;
+
+    #[derive(Callable)]
+    #[argcall(output = i32, struct_vis = "pub(crate)")]
+    pub enum Shared {
+        #[argcall(fn = 1)]
+        One,
+    }
+
+    #[derive(Callable)]
+    #[argcall(output = i32, struct_vis = "pub(crate)")]
+    pub enum Generic<T: Clone> {
+        #[argcall(fn = 2)]
+        Two,
+        #[argcall(fn = 0)]
+        Value { value: T },
+    }
+
+    #[derive(Callable)]
+    #[argcall(output = i32)]
+    pub enum Hidden {
+        #[argcall(fn = 3)]
+        Three,
+    }
+
+    pub fn hidden_struct() -> i32 {
+        HiddenThreeCallable.call_fn(())
+    }
+}
+
+#[test]
+fn test_struct_vis() {
+    assert_eq!(commands::SharedOneCallable.call_fn(()), 1);
+    assert_eq!(
+        commands::GenericTwoCallable::<u8>(core::marker::PhantomData).call_fn(()),
+        2
+    );
+    assert_eq!(commands::Shared::One.call_fn(()), 1);
+    assert_eq!(commands::Generic::<u8>::Two.call_fn(()), 2);
+    assert_eq!(commands::Generic::Value { value: 'x' }.call_fn(()), 0);
+}
+
+#[test]
+fn test_default_private() {
+    assert_eq!(commands::Hidden::Three.call_fn(()), 3);
+    assert_eq!(commands::hidden_struct(), 3);
+}
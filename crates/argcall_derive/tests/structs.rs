@@ -0,0 +1,75 @@
+use argcall::{Callable, CallableMut, CallableOnce};
+
+#[derive(Callable)]
+#[argcall(output = i32, fn = 1)]
+struct Unit;
+
+#[derive(Callable)]
+#[argcall(output = i32, fn = _0 * 2)]
+struct Tuple(i32);
+
+#[derive(Callable)]
+#[argcall(output = i32, fn = self.x + self.y)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, fn = x * y)]
+struct Bindings {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32, fn_path = "add")]
+struct Path {
+    x: i32,
+    y: i32,
+}
+
+#[derive(CallableMut)]
+#[argcall(output = i32, fn = { *count += 1; *count })]
+struct Counter {
+    count: i32,
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = String, fn = name)]
+struct Owned {
+    name: String,
+}
+
+fn add(x: &i32, y: &i32) -> i32 {
+    x + y
+}
+
+#[test]
+fn test_unit_struct() {
+    assert_eq!(Unit.call_fn(()), 1);
+}
+
+#[test]
+fn test_tuple_struct() {
+    assert_eq!(Tuple(21).call_fn(()), 42);
+}
+
+#[test]
+fn test_named_struct() {
+    assert_eq!(Point { x: 1, y: 2 }.call_fn(()), 3);
+    assert_eq!(Bindings { x: 2, y: 3 }.call_fn(()), 6);
+    assert_eq!(Path { x: 2, y: 3 }.call_fn(()), 5);
+}
+
+#[test]
+fn test_struct_mut_and_once() {
+    let mut counter = Counter { count: 0 };
+    assert_eq!(counter.call_fn_mut(()), 1);
+    assert_eq!(counter.call_fn_mut(()), 2);
+
+    let owned = Owned {
+        name: "argcall".to_string(),
+    };
+    assert_eq!(owned.call_fn_once(()), "argcall");
+}
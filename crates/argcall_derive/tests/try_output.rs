@@ -0,0 +1,27 @@
+use argcall::{Callable, TryCallable};
+use std::num::ParseIntError;
+
+#[derive(Callable)]
+#[argcall(try_output = i32, error = ParseIntError)]
+enum Parse {
+    #[argcall(fn = text.parse())]
+    Text { text: &'static str },
+    #[argcall(fn = Ok(0))]
+    Zero,
+}
+
+fn double(p: &Parse) -> Result<i32, ParseIntError> {
+    Ok(p.try_call(())? * 2)
+}
+
+#[test]
+fn test_try_output_success() {
+    assert_eq!(double(&Parse::Text { text: "21" }), Ok(42));
+    assert_eq!(double(&Parse::Zero), Ok(0));
+}
+
+#[test]
+fn test_try_output_error_propagates() {
+    let err = double(&Parse::Text { text: "nope" }).unwrap_err();
+    assert_eq!(err, "nope".parse::<i32>().unwrap_err());
+}
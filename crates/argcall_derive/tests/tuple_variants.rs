@@ -0,0 +1,211 @@
+use argcall::{Callable, CallableMut, CallableOnce};
+
+#[derive(Callable)]
+#[argcall(output = usize)]
+enum Msg {
+    #[argcall(fn = str::len(_0))]
+    Text(String),
+    #[argcall(fn_path = "sum")]
+    Pair(usize, usize),
+    #[argcall(fn = combine(_0, _1))]
+    Repeat(String, usize),
+    #[argcall(fn = 0)]
+    Empty,
+}
+
+fn sum(a: &usize, b: &usize) -> usize {
+    a + b
+}
+
+fn combine(text: &str, count: &usize) -> usize {
+    text.len() * count
+}
+
+struct Inner;
+
+impl Callable for Inner {
+    type Output = usize;
+    fn call_fn(&self, _: ()) -> usize {
+        7
+    }
+}
+
+#[derive(Callable)]
+#[argcall(output = usize)]
+enum Mixed {
+    Delegate(Inner),
+    #[argcall(fn = _0.len())]
+    Bound(Vec<u8>),
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = String)]
+enum Owned {
+    #[argcall(fn = _0)]
+    Text(String),
+}
+
+// Implements only `CallableOnce`, so it can only be delegated to by value
+struct Take(Vec<u8>);
+
+impl CallableOnce<(u8,)> for Take {
+    type Output = Vec<u8>;
+    fn call_fn_once(mut self, (byte,): (u8,)) -> Vec<u8> {
+        self.0.push(byte);
+        self.0
+    }
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = Vec<u8>, args = (byte: u8))]
+enum Consume {
+    Take(Take),
+    #[argcall(fn = vec![byte])]
+    Single,
+}
+
+// Implements only `CallableMut`, so it can only be delegated to by mutable reference
+struct Counter(u32);
+
+impl CallableMut for Counter {
+    type Output = u32;
+    fn call_fn_mut(&mut self, _: ()) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+#[derive(CallableMut)]
+#[argcall(output = u32)]
+enum Count {
+    Counter(Counter),
+}
+
+// Borrowed with `delegate = "ref"`, so the inner value stays in the enum while it is called
+#[derive(CallableMut)]
+#[argcall(output = usize)]
+enum Borrowing {
+    #[argcall(delegate = "ref")]
+    Shared(Inner),
+    #[argcall(delegate = "value")]
+    Exclusive(Inner),
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = Vec<u8>, args = (byte: u8))]
+enum Delegated {
+    #[argcall(delegate = "ref")]
+    Borrowed(Pushed),
+    #[argcall(delegate = "value")]
+    Take(Take),
+}
+
+struct Pushed(Vec<u8>);
+
+impl Callable<(u8,)> for Pushed {
+    type Output = Vec<u8>;
+    fn call_fn(&self, (byte,): (u8,)) -> Vec<u8> {
+        let mut bytes = self.0.clone();
+        bytes.push(byte);
+        bytes
+    }
+}
+
+#[test]
+fn test_bound_fields() {
+    assert_eq!(Msg::Text("hello".to_string()).call_fn(()), 5);
+    assert_eq!(Msg::Pair(2, 3).call_fn(()), 5);
+    assert_eq!(Msg::Repeat("ab".to_string(), 3).call_fn(()), 6);
+    assert_eq!(Msg::Empty.call_fn(()), 0);
+}
+
+#[test]
+fn test_delegate_and_bound() {
+    assert_eq!(Mixed::Delegate(Inner).call_fn(()), 7);
+    assert_eq!(Mixed::Bound(vec![1, 2, 3]).call_fn(()), 3);
+}
+
+#[test]
+fn test_once_moves_fields() {
+    assert_eq!(Owned::Text("moved".to_string()).call_fn_once(()), "moved");
+}
+
+#[test]
+fn test_delegate_once_moves_inner() {
+    assert_eq!(Consume::Take(Take(vec![1])).call_fn_once((2,)), [1, 2]);
+    assert_eq!(Consume::Single.call_fn_once((3,)), [3]);
+}
+
+#[test]
+fn test_delegate_mut_borrows_inner() {
+    let mut count = Count::Counter(Counter(0));
+    assert_eq!(count.call_fn_mut(()), 1);
+    assert_eq!(count.call_fn_mut(()), 2);
+    let Count::Counter(counter) = count;
+    assert_eq!(counter.0, 2);
+}
+
+#[test]
+fn test_delegate_modes() {
+    assert_eq!(Borrowing::Shared(Inner).call_fn_mut(()), 7);
+    assert_eq!(Borrowing::Exclusive(Inner).call_fn_mut(()), 7);
+
+    assert_eq!(
+        Delegated::Borrowed(Pushed(vec![1])).call_fn_once((2,)),
+        [1, 2]
+    );
+    assert_eq!(Delegated::Take(Take(vec![3])).call_fn_once((4,)), [3, 4]);
+}
+
+#[derive(Callable)]
+#[argcall(output = i64, args = (n: i64))]
+enum Step {
+    #[argcall(fn = n + 1)]
+    Increment,
+    #[argcall(output = i32, fn = *by)]
+    Fixed { by: i32 },
+}
+
+#[derive(Callable)]
+#[argcall(args = (n: i64))]
+enum Pipeline<F: Callable<(i64,), Output = i64>> {
+    Step(Step),
+    #[argcall(fn = n * 2)]
+    Double,
+    Custom(F),
+    #[argcall(fn = n * *factor)]
+    Scale {
+        factor: i64,
+    },
+    #[argcall(output = i32, fn = *_0)]
+    Constant(i32),
+    Nested(Box<Step>),
+}
+
+impl Callable<(i64,)> for Box<Step> {
+    type Output = i64;
+    fn call_fn(&self, args: (i64,)) -> i64 {
+        (**self).call_fn(args)
+    }
+}
+
+#[test]
+fn test_mixed_delegation_and_fn() {
+    fn call(pipeline: Pipeline<fn(i64) -> i64>) -> i64 {
+        pipeline.call_fn((5,))
+    }
+    fn negate(n: i64) -> i64 {
+        -n
+    }
+    assert_eq!(call(Pipeline::Step(Step::Increment)), 6);
+    assert_eq!(call(Pipeline::Step(Step::Fixed { by: 3 })), 3);
+    assert_eq!(call(Pipeline::Double), 10);
+    assert_eq!(call(Pipeline::Custom(negate)), -5);
+    assert_eq!(call(Pipeline::Scale { factor: 3 }), 15);
+    assert_eq!(call(Pipeline::Constant(7)), 7);
+    assert_eq!(call(Pipeline::Nested(Box::new(Step::Increment))), 6);
+    assert_eq!(
+        PipelineDoubleCallable::<fn(i64) -> i64>(core::marker::PhantomData).call_fn((4,)),
+        8
+    );
+}
@@ -0,0 +1,14 @@
+use argcall::Callable;
+
+fn make(_: &i32, _: &i32) -> i32 {
+    0
+}
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    #[argcall(fn_path = "make", arg_order = [b, c])]
+    Named { a: i32, b: i32 },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    #[argcall(fn = *a, arg_order = [a])]
+    Named { a: i32 },
+}
+
+fn main() {}
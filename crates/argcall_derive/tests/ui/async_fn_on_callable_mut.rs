@@ -0,0 +1,14 @@
+use argcall::CallableMut;
+
+#[derive(CallableMut)]
+#[argcall(output = i32)]
+enum Task {
+    #[argcall(async_fn = work())]
+    Work,
+}
+
+async fn work() -> i32 {
+    1
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    #[argcall(fn = {
+        let value = 1;
+        value.to_string()
+    })]
+    One,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use argcall::AsyncCallable;
+
+#[derive(AsyncCallable)]
+#[argcall(output = i32, box_output)]
+enum MyEnum {
+    #[argcall(fn = async { 1 })]
+    One,
+}
+
+fn main() {}
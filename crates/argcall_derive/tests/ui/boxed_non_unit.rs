@@ -0,0 +1,12 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, boxed)]
+enum MyEnum {
+    #[argcall(fn = 1)]
+    One,
+    #[argcall(fn = *x)]
+    Value { x: i32 },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, call_all)]
+enum MyEnum {
+    #[argcall(fn = 1)]
+    One,
+}
+
+fn main() {}
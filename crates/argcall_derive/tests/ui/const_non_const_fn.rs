@@ -0,0 +1,14 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = u32, const)]
+enum Size {
+    #[argcall(fn = compute())]
+    Computed,
+}
+
+fn compute() -> u32 {
+    1
+}
+
+fn main() {}
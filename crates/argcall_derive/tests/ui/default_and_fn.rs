@@ -0,0 +1,10 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    #[argcall(default, fn = 1)]
+    One,
+}
+
+fn main() {}
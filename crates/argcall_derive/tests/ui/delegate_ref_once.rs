@@ -0,0 +1,20 @@
+use argcall::CallableOnce;
+
+// Implements only `CallableOnce`, so it cannot be borrowed with `delegate = "ref"`
+struct Take(Vec<u8>);
+
+impl CallableOnce for Take {
+    type Output = Vec<u8>;
+    fn call_fn_once(self, _: ()) -> Vec<u8> {
+        self.0
+    }
+}
+
+#[derive(CallableOnce)]
+#[argcall(output = Vec<u8>)]
+enum Consume {
+    #[argcall(delegate = "ref")]
+    Take(Take),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, dispatch)]
+enum MyEnum {
+    #[argcall(fn = 1)]
+    Unit,
+    #[argcall(fn = *x)]
+    Named { x: i32 },
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    #[argcall(fn = 1)]
+    #[argcall(fn = 2)]
+    Unit,
+}
+
+fn main() {}
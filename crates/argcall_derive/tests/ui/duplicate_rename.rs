@@ -0,0 +1,19 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, dispatch)]
+enum MyEnum {
+    #[argcall(fn = 1)]
+    One,
+    #[argcall(fn = 2, rename = "One")]
+    Two,
+}
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum NoDispatch {
+    #[argcall(fn = 1, rename = "one")]
+    One,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum Command {
+    #[argcall(fn = 1, struct_name = "Action")]
+    One,
+    #[argcall(fn = 2, struct_name = "Action")]
+    Two,
+}
+
+fn main() {}
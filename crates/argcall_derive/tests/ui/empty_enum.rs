@@ -0,0 +1,7 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum Empty {}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(enum_output(Iterator))]
+enum Numbers {
+    #[argcall(fn = 0..3, output = std::ops::Range<i32>)]
+    Range,
+    #[argcall(fn = vec![1].into_iter())]
+    List,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(enum_output(Iterator, Future))]
+enum Numbers {
+    #[argcall(fn = 0..3, output = std::ops::Range<i32>)]
+    Range,
+}
+
+fn main() {}
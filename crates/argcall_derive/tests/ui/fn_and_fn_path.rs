@@ -0,0 +1,14 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    #[argcall(fn = one(), fn_path = "one")]
+    Unit,
+}
+
+fn one() -> i32 {
+    1
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, ref, fn_traits)]
+enum MyEnum {
+    #[argcall(fn = 1)]
+    One,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, struct_vis = "public")]
+enum Command {
+    #[argcall(fn = 1)]
+    One,
+}
+
+fn main() {}
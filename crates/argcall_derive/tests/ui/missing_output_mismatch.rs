@@ -0,0 +1,13 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+enum MyEnum {
+    #[argcall(fn = one())]
+    Unit,
+}
+
+fn one() -> i32 {
+    1
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    Unit,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, no_structs)]
+enum Op {
+    #[argcall(fn = 1)]
+    One,
+}
+
+fn main() {
+    OpOneCallable.call_fn(());
+}
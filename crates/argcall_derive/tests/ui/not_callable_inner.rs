@@ -0,0 +1,13 @@
+use argcall::Callable;
+
+struct NotCallable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    #[argcall(fn = 1)]
+    One,
+    Inner(NotCallable),
+}
+
+fn main() {}
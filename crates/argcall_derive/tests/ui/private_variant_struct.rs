@@ -0,0 +1,14 @@
+mod commands {
+    use argcall::Callable;
+
+    #[derive(Callable)]
+    #[argcall(output = i32)]
+    pub enum Command {
+        #[argcall(fn = 1)]
+        One,
+    }
+}
+
+fn main() {
+    let _ = commands::CommandOneCallable;
+}
@@ -0,0 +1,7 @@
+use argcall::CallableMut;
+
+#[derive(CallableMut)]
+#[argcall(ref, output = &'a str, fn = "value")]
+struct Value;
+
+fn main() {}
@@ -0,0 +1,7 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, args = ((x, 1): (i32, i32)), fn = x)]
+struct Refutable;
+
+fn main() {}
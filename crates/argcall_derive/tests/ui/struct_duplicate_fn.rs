@@ -0,0 +1,12 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, fn = 1)]
+#[argcall(fn_path = "one")]
+struct Unit;
+
+fn one() -> i32 {
+    1
+}
+
+fn main() {}
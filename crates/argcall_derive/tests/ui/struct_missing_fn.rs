@@ -0,0 +1,12 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+struct MyStruct {
+    x: i32,
+}
+
+#[derive(Callable)]
+struct NoAttribute;
+
+fn main() {}
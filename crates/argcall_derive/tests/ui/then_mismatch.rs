@@ -0,0 +1,15 @@
+use argcall::Callable;
+
+struct Len;
+
+impl Callable<(String,)> for Len {
+    type Output = usize;
+
+    fn call_fn(&self, (s,): (String,)) -> Self::Output {
+        s.len()
+    }
+}
+
+fn main() {
+    let _ = (|| 1).then(Len);
+}
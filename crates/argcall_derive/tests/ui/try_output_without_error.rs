@@ -0,0 +1,10 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(try_output = i32)]
+enum MyEnum {
+    #[argcall(fn = Ok(1))]
+    Unit,
+}
+
+fn main() {}
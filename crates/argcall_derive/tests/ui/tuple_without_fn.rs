@@ -0,0 +1,9 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+enum MyEnum {
+    Pair(i32, i32),
+}
+
+fn main() {}
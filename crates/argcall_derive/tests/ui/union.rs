@@ -0,0 +1,10 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32)]
+union MyUnion {
+    a: i32,
+    b: u32,
+}
+
+fn main() {}
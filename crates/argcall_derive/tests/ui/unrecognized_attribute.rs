@@ -0,0 +1,10 @@
+use argcall::Callable;
+
+#[derive(Callable)]
+#[argcall(output = i32, unknown = 1)]
+enum MyEnum {
+    #[argcall(fn = 1)]
+    Unit,
+}
+
+fn main() {}
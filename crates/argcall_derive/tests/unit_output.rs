@@ -0,0 +1,51 @@
+use argcall::{Callable, CallableMut};
+use std::cell::Cell;
+
+thread_local! {
+    static CALLS: Cell<u32> = const { Cell::new(0) };
+}
+
+fn record() {
+    CALLS.with(|calls| calls.set(calls.get() + 1));
+}
+
+#[derive(Callable)]
+enum Event {
+    #[argcall(fn = record())]
+    Click,
+    #[argcall(fn = println!("key {}", key))]
+    Key { key: char },
+    #[argcall(fn = { record(); record(); })]
+    DoubleClick,
+}
+
+#[derive(CallableMut)]
+#[argcall(args = (by: u32))]
+enum Counter {
+    #[argcall(fn = *count += by)]
+    Up { count: u32 },
+}
+
+#[derive(Callable)]
+#[argcall(fn = record())]
+struct Ping;
+
+#[test]
+fn test_unit_output_by_default() {
+    CALLS.with(|calls| calls.set(0));
+    let () = Event::Click.call_fn(());
+    Event::Key { key: 'a' }.call_fn(());
+    Event::DoubleClick.call_fn(());
+    EventClickCallable.call_fn(());
+    Ping.call_fn(());
+    assert_eq!(CALLS.with(Cell::get), 5);
+}
+
+#[test]
+fn test_unit_output_with_args() {
+    let mut counter = Counter::Up { count: 1 };
+    counter.call_fn_mut((2,));
+    counter.call_fn_mut((3,));
+    let Counter::Up { count } = counter;
+    assert_eq!(count, 6);
+}
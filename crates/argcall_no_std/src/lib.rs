@@ -0,0 +1,53 @@
+//! Checks that `argcall` and the code generated by its derive macros build under `#![no_std]`.
+#![no_std]
+
+use argcall::{Callable, CallableMut, CallableOnce};
+
+#[derive(Callable)]
+#[argcall(output = u32, args = (n: u32))]
+pub enum Op {
+    #[argcall(fn = n + 1)]
+    Increment,
+    #[argcall(fn = n * factor)]
+    Scale {
+        factor: u32,
+    },
+    Custom(Square),
+}
+
+#[derive(Callable)]
+#[argcall(output = u32, args = (n: u32), fn = n * n)]
+pub struct Square;
+
+#[derive(CallableMut)]
+#[argcall(output = u32, fn = { *count += 1; *count })]
+pub struct Counter {
+    count: u32,
+}
+
+#[derive(CallableOnce)]
+#[argcall(try_output = u32, error = &'static str)]
+pub enum Checked {
+    #[argcall(fn = if value > 0 { Ok(value) } else { Err("zero") })]
+    Value { value: u32 },
+}
+
+pub fn run() -> u32 {
+    let mut counter = Counter { count: 0 };
+    counter.call_fn_mut(());
+    Op::Increment.call_fn((1,))
+        + Op::Scale { factor: 2 }.call_fn((3,))
+        + Op::Custom(Square).call_fn((4,))
+        + counter.call_fn_mut(())
+        + Checked::Value { value: 5 }.call_fn_once(()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        assert_eq!(run(), 2 + 6 + 16 + 2 + 5);
+    }
+}